@@ -0,0 +1,78 @@
+// Times frames of a big scene with and without `Viewport3d::with_batching`, from drawing the scene to the
+// triangles egui hands over to the renderer, without opening a window.
+// `cargo run --release --example batching`
+
+use std::time::{Duration, Instant};
+
+use egui_draw3d::camera::Perspective;
+use egui_draw3d::scene::Scene;
+use egui_draw3d::shapes::{point_cloud::PointCloud, polymesh::Polymesh};
+use egui_draw3d::widgets::viewport3d::Viewport3d;
+use glam::{Vec3, Mat4};
+
+const FRAMES: usize = 20;
+const GRID: usize = 300; // quads per side of the wireframe mesh
+const POINTS: usize = 200_000;
+
+fn main() {
+    let scene = big_scene();
+    let camera = Perspective::new(0.35, Mat4::from_translation(Vec3::new(0.0, -6.0, 14.0)) * Mat4::from_rotation_x(0.4), 0.1, 100.0);
+    let input = || egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::Vec2::new(1280.0, 720.0))),
+        ..Default::default()
+    };
+
+    println!("{} faces in wireframe and {} points", GRID * GRID, POINTS);
+    for batch in [false, true] {
+        let ctx = egui::Context::default();
+        // time spent drawing the scene into shapes and then tessellating those into triangles
+        let frame = || {
+            let start = Instant::now();
+            let output = ctx.run(input(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    Viewport3d::new(&scene, &camera).with_size(1200, 680).with_batching(batch).show(ui);
+                });
+            });
+            let drawn = start.elapsed();
+            let primitives = ctx.tessellate(output.shapes, output.pixels_per_point);
+            assert!(!primitives.is_empty());
+            (drawn, start.elapsed() - drawn)
+        };
+        frame(); // loads the fonts
+        let (mut draw, mut tessellate): (Vec<Duration>, Vec<Duration>) = (0..FRAMES).map(|_| frame()).unzip();
+        let mut total: Vec<Duration> = draw.iter().zip(tessellate.iter()).map(|(d, t)| *d + *t).collect();
+        draw.sort();
+        tessellate.sort();
+        total.sort();
+        println!("{:<12} draw {:>8.1?}  tessellate {:>8.1?}  total {:>8.1?}  (medians)",
+            if batch { "batched" } else { "not batched" }, draw[FRAMES / 2], tessellate[FRAMES / 2], total[FRAMES / 2]);
+    }
+}
+
+// a wireframe grid mesh bent into a wave and a point cloud spiral above it
+fn big_scene() -> Scene {
+    let size = 10.0;
+    let points: Vec<Vec3> = (0..=GRID).flat_map(|z| (0..=GRID).map(move |x| {
+        let (u, v) = (x as f32 / GRID as f32 - 0.5, z as f32 / GRID as f32 - 0.5);
+        Vec3::new(u * size, (u * 12.0).sin() * (v * 9.0).cos() * 0.3, v * size)
+    })).collect();
+    let mut indices = Vec::with_capacity(GRID * GRID * 4);
+    for z in 0..GRID {
+        for x in 0..GRID {
+            let i = z * (GRID + 1) + x;
+            indices.extend([i, i + 1, i + GRID + 2, i + GRID + 1]);
+        }
+    }
+    let mesh = Polymesh::new(None, Mat4::IDENTITY, 1.0, egui::Color32::LIGHT_GRAY, points, indices, vec![4; GRID * GRID]);
+
+    let spiral: Vec<Vec3> = (0..POINTS).map(|i| {
+        let t = i as f32 / POINTS as f32;
+        Vec3::new((t * 400.0).cos() * (1.0 + t * 3.0), 1.0 + t * 3.0, (t * 400.0).sin() * (1.0 + t * 3.0))
+    }).collect();
+    let cloud = PointCloud::new(None, Mat4::IDENTITY, 0.01, egui::Color32::LIGHT_GREEN, spiral);
+
+    let mut scene = Scene::new();
+    scene.add(Box::new(mesh));
+    scene.add(Box::new(cloud));
+    scene
+}
@@ -4,8 +4,15 @@
 
 use egui;
 use egui_draw3d::widgets::viewport3d::Viewport3d;
+use egui_draw3d::widgets::gizmo::{Gizmo, GizmoMode, GizmoSpace};
 use egui_draw3d::shapes;
 use egui_draw3d::camera;
+use egui_draw3d::navigation::{CameraController, NavigationPreset};
+use egui_draw3d::draw_list::DepthSort;
+use egui_draw3d::scene::{Scene, Node};
+use egui_draw3d::selection::Selection;
+use egui_draw3d::colormap::{Colormap, ScalarMap};
+use egui_draw3d::widgets::legend::Legend;
 use glam::{Vec3, Mat4, Quat};
 
 
@@ -24,15 +31,19 @@ fn main() -> eframe::Result<()> {
 }
 
 pub struct TemplateApp {
-    sensitivity: f32,
-    camera_xform: Mat4 //stores current xform of camera so we can modify it by panning etc 
+    scene: Scene,
+    selection: Selection,
+    gizmo_mode: GizmoMode,
+    camera: camera::Perspective, // initial xform of the camera, the viewport controller takes over from there
 }
 
 impl Default for TemplateApp {
     fn default() -> Self {
         Self {
-            sensitivity: 0.01,
-            camera_xform:  Mat4::from_scale_rotation_translation(Vec3::ONE, Quat::IDENTITY, Vec3::new(0.0, -1.0, 10.0) ) //camera Y translation has to be flipped to match 
+            scene: example_scene(),
+            selection: Selection::new(),
+            gizmo_mode: GizmoMode::Translate,
+            camera: camera::Perspective::new(0.35, Mat4::from_scale_rotation_translation(Vec3::ONE, Quat::IDENTITY, Vec3::new(0.0, -1.0, 10.0) ), 0.01, 30.0), //camera Y translation has to be flipped to match 
         }
     }
 }
@@ -66,161 +77,168 @@ impl eframe::App for TemplateApp {
             });
         });
         
-         //creating some points we will use later in poincloud shape
-        let example_pointcloud: Vec<Vec3> = (0..90).into_iter().map(|pi| {let u = pi as f32/90.0; Vec3::new((u*10.0).sin(), (u*10.0).cos(), u) }).collect();
-        
-        //example mesh data copied from usd file
-        let example_mesh_points: Vec<Vec3> = [
-            (0.001, 0.288802, -0.010508), (0.001, 0.288802, 0.012508), (-0.088934, 0.141844, -0.010508), (-0.088934, 0.141844, 0.012508), (-0.10251, 0.16695501, -0.010508), (-0.10251, 0.16695501, 0.012508), (-0.099001996, 0.216273, -0.010508), (-0.099001996, 0.216273, 0.012508), (-0.075356, 0.25719, -0.010508), (-0.075356, 0.25719, 0.012508), (-0.040170003, 0.281869, -0.010508), (-0.040170003, 0.281869, 0.012508), (-0.071535, 0.149978, -0.007495), (-0.071535, 0.149978, 0.009495), (0.090934, 0.141844, -0.010508), (0.090934, 0.141844, 0.012508), (0.10451, 0.16695501, -0.010508), (0.10451, 0.16695501, 0.012508), (0.101002, 0.216273, -0.010508), (0.101002, 0.216273, 0.012508), (0.077356, 0.25719, -0.010508), (0.077356, 0.25719, 0.012508), (0.04217, 0.281869, -0.010508), (0.04217, 0.281869, 0.012508), (0.073535, 0.149978, -0.007495), (0.073535, 0.149978, 0.009495), (0.001, 0.278579, -0.008928), (0.001, 0.278579, 0.010928), (-0.087132, 0.150511, -0.008928), (-0.087132, 0.150511, 0.010928), (-0.095061, 0.169825, -0.008928), (-0.095061, 0.169825, 0.010928), (-0.090967, 0.213088, -0.008928), (-0.090967, 0.213088, 0.010928), (-0.069465995, 0.249843, -0.008928), (-0.069465995, 0.249843, 0.010928), (-0.03725, 0.27228698, -0.008928), (-0.03725, 0.27228698, 0.010928), (-0.075171, 0.15686901, -0.006408), (-0.075171, 0.15686901, 0.008408), (0.089132, 0.150511, -0.008928), (0.089132, 0.150511, 0.010928), (0.097061, 0.169825, -0.008928), (0.097061, 0.169825, 0.010928), (0.092967, 0.213088, -0.008928), (0.092967, 0.213088, 0.010928), (0.071466, 0.249843, -0.008928), (0.071466, 0.249843, 0.010928), (0.039249998, 0.27228698, -0.008928), (0.039249998, 0.27228698, 0.010928), (0.077171005, 0.15686901, -0.006408), (0.077171005, 0.15686901, 0.008408), (0.001, 0.289951, 0.001), (-0.089136, 0.140869, 0.001), (-0.097366996, 0.149645, -0.010508), (-0.097366996, 0.149645, 0.012508), (-0.103346996, 0.166632, 0.001), (-0.103316, 0.190833, -0.010508), (-0.103316, 0.190833, 0.012508), (-0.099905, 0.21663, 0.001), (-0.089253, 0.238682, -0.010508), (-0.089253, 0.238682, 0.012508), (-0.076018, 0.258016, 0.001), (-0.058786, 0.27174598, -0.010508), (-0.058786, 0.27174598, 0.012508), (-0.040499, 0.282946, 0.001), (-0.019992, 0.28726298, -0.010508), (-0.019992, 0.28726298, 0.012508), (-0.069616, 0.15089901, 0.001), (-0.078756995, 0.144234, 0.012027), (-0.078756995, 0.144234, -0.010027), (0.091136, 0.140869, 0.001), (0.099367, 0.149645, -0.010508), (0.099367, 0.149645, 0.012508), (0.105347, 0.166632, 0.001), (0.105316006, 0.190833, -0.010508), (0.105316006, 0.190833, 0.012508), (0.101905, 0.21663, 0.001), (0.091253005, 0.238682, -0.010508), (0.091253005, 0.238682, 0.012508), (0.078018, 0.258016, 0.001), (0.060785998, 0.27174598, -0.010508), (0.060785998, 0.27174598, 0.012508), (0.042499, 0.282946, 0.001), (0.021992, 0.28726298, -0.010508), (0.021992, 0.28726298, 0.012508), (0.071616, 0.15089901, 0.001), (0.080757, 0.144234, 0.012027), (0.080757, 0.144234, -0.010027), (0.001, 0.277053, 0.001), (-0.086863995, 0.151804, 0.001), (-0.092300996, 0.155898, -0.008928), (-0.092300996, 0.155898, 0.010928), (-0.093949996, 0.17025301, 0.001), (-0.094999, 0.190464, -0.008928), (-0.094999, 0.190464, 0.010928), (-0.089768, 0.212613, 0.001), (-0.082109, 0.233168, -0.008928), (-0.082109, 0.233168, 0.010928), (-0.068587, 0.24874601, 0.001), (-0.05435, 0.26305598, -0.008928), (-0.05435, 0.26305598, 0.010928), (-0.036815003, 0.27085698, 0.001), (-0.018548999, 0.27719, -0.008928), (-0.018548999, 0.27719, 0.010928), (-0.074342996, 0.158547, 0.001), (-0.080335, 0.153165, 0.010506), (-0.080335, 0.153165, -0.008506), (0.088864, 0.151804, 0.001), (0.094301, 0.155898, -0.008928), (0.094301, 0.155898, 0.010928), (0.09595, 0.17025301, 0.001), (0.096999004, 0.190464, -0.008928), (0.096999004, 0.190464, 0.010928), (0.091768004, 0.212613, 0.001), (0.084109, 0.233168, -0.008928), (0.084109, 0.233168, 0.010928), (0.070587, 0.24874601, 0.001), (0.056349996, 0.26305598, -0.008928), (0.056349996, 0.26305598, 0.010928), (0.038815, 0.27085698, 0.001), (0.020549, 0.27719, -0.008928), (0.020549, 0.27719, 0.010928), (0.076343, 0.158547, 0.001), (0.082335, 0.153165, 0.010506), (0.082335, 0.153165, -0.008506), (0.001, 0.28350198, -0.012522), (0.001, 0.28350198, 0.014522), (-0.088, 0.146337, -0.012522), (-0.088, 0.146337, 0.014522), (-0.098648995, 0.16844301, -0.012522), (-0.098648995, 0.16844301, 0.014522), (-0.094836, 0.214622, -0.012522), (-0.094836, 0.214622, 0.014522), (-0.072303, 0.25338098, -0.012522), (-0.072303, 0.25338098, 0.014522), (-0.038657002, 0.27690098, -0.012522), (-0.038657002, 0.27690098, 0.014522), (-0.072743, 0.154354, -0.008006), (-0.072743, 0.154354, 0.010006), (0.09, 0.146337, -0.012522), (0.09, 0.146337, 0.014522), (0.100649, 0.16844301, -0.012522), (0.100649, 0.16844301, 0.014522), (0.096836, 0.214622, -0.012522), (0.096836, 0.214622, 0.014522), (0.074303, 0.25338098, -0.012522), (0.074303, 0.25338098, 0.014522), (0.040657, 0.27690098, -0.012522), (0.040657, 0.27690098, 0.014522), (0.074743, 0.154354, -0.008006), (0.074743, 0.154354, 0.010006), (-0.097936995, 0.14894201, 0.001), (-0.10425, 0.190874, 0.001), (-0.090055995, 0.23930201, 0.001), (-0.059285, 0.272723, 0.001), (-0.020155, 0.288395, 0.001), (-0.078347, 0.143397, 0.001), (0.099937, 0.14894201, 0.001), (0.10625, 0.190874, 0.001), (0.092056, 0.23930201, 0.001), (0.061284997, 0.272723, 0.001), (0.022155, 0.288395, 0.001), (0.080347, 0.143397, 0.001), (-0.091545, 0.156831, 0.001), (-0.093757994, 0.190409, 0.001), (-0.081043, 0.232345, 0.001), (-0.053688, 0.26175898, 0.001), (-0.018334, 0.27568698, 0.001), (-0.080421, 0.15462, 0.001), (0.093545005, 0.156831, 0.001), (0.095758, 0.190409, 0.001), (0.083043, 0.232345, 0.001), (0.055687997, 0.26175898, 0.001), (0.020334, 0.27568698, 0.001), (0.082421005, 0.15462, 0.001), (-0.094740994, 0.152886, -0.012522), (-0.094740994, 0.152886, 0.014522), (-0.099004, 0.190641, -0.012522), (-0.099004, 0.190641, 0.014522), (-0.085549, 0.235823, -0.012522), (-0.085549, 0.235823, 0.014522), (-0.056486003, 0.267241, -0.012522), (-0.056486003, 0.267241, 0.014522), (-0.019244, 0.28204098, -0.012522), (-0.019244, 0.28204098, 0.014522), (-0.0703, 0.156645, 0.001), (-0.079601996, 0.148903, 0.013956), (-0.079601996, 0.148903, -0.011956), (0.096741, 0.152886, -0.012522), (0.096741, 0.152886, 0.014522), (0.101004004, 0.190641, -0.012522), (0.101004004, 0.190641, 0.014522), (0.087549, 0.235823, -0.012522), (0.087549, 0.235823, 0.014522), (0.058486, 0.267241, -0.012522), (0.058486, 0.267241, 0.014522), (0.021244, 0.28204098, -0.012522), (0.021244, 0.28204098, 0.014522), (0.0723, 0.156645, 0.001), (0.081602, 0.148903, 0.013956), (0.081602, 0.148903, -0.011956), (0.001, -0.0007819999, -0.033839002), (0.001, 0.125506, -0.092255), (0.014332, -0.0007819999, -0.031187), (0.036686998, 0.125506, -0.085157), (0.025635, -0.0007819999, -0.023635), (0.066941, 0.125506, -0.064941), (0.033187, -0.0007819999, -0.012332), (0.087157, 0.125506, -0.034687), (0.035839, -0.0007819999, 0.001), (0.094255, 0.125506, 0.001), (0.033187, -0.0007819999, 0.014332), (0.087157, 0.125506, 0.036686998), (0.025635, -0.0007819999, 0.025635), (0.066941, 0.125506, 0.066941), (0.014332, -0.0007819999, 0.033187), (0.036686998, 0.125506, 0.087157), (0.001, -0.0007819999, 0.035839), (0.001, 0.125506, 0.094255), (-0.012332, -0.0007819999, 0.033187), (-0.034687, 0.125506, 0.087157), (-0.023635, -0.0007819999, 0.025635), (-0.064941, 0.125506, 0.066941), (-0.031187, -0.0007819999, 0.014332), (-0.085157, 0.125506, 0.036686998), (-0.033839002, -0.0007819999, 0.001), (-0.092255, 0.125506, 0.001), (-0.031187, -0.0007819999, -0.012332), (-0.085157, 0.125506, -0.034687), (-0.023635, -0.0007819999, -0.023635), (-0.064941, 0.125506, -0.064941), (-0.012332, -0.0007819999, -0.031187), (-0.034687, 0.125506, -0.085157), (0.017227, 0.165286, -0.038176), (0.001, 0.165286, -0.041403003), (0.030984, 0.165286, -0.028983999), (0.040175997, 0.165286, -0.015226999), (0.043403, 0.165286, 0.001), (0.040175997, 0.165286, 0.017227), (0.030984, 0.165286, 0.030984), (0.017227, 0.165286, 0.040175997), (0.001, 0.165286, 0.043403), (-0.015226999, 0.165286, 0.040175997), (-0.028983999, 0.165286, 0.030984), (-0.038176, 0.165286, 0.017227), (-0.041403003, 0.165286, 0.001), (-0.038176, 0.165286, -0.015226999), (-0.028983999, 0.165286, -0.028983999), (-0.015226999, 0.165286, -0.038176), (0.001, 0.006556, -0.044526003), (0.018422, 0.006556, -0.041061003), (0.033191998, 0.006556, -0.031192), (0.043061, 0.006556, -0.016422), (0.046526, 0.006556, 0.001), (0.043061, 0.006556, 0.018422), (0.033191998, 0.006556, 0.033191998), (0.018422, 0.006556, 0.043061), (0.001, 0.006556, 0.046526), (-0.016422, 0.006556, 0.043061), (-0.031192, 0.006556, 0.033191998), (-0.041061003, 0.006556, 0.018422), (-0.044526003, 0.006556, 0.001), (-0.041061003, 0.006556, -0.016422), (-0.031192, 0.006556, -0.031192), (-0.016422, 0.006556, -0.041061003), (0.024524, 0.158773, -0.055792), (0.001, 0.158773, -0.060471002), (0.044467, 0.158773, -0.042466003), (0.057791997, 0.158773, -0.022524), (0.062471, 0.158773, 0.001), (0.057791997, 0.158773, 0.024524), (0.044467, 0.158773, 0.044466), (0.024524, 0.158773, 0.057791997), (0.001, 0.158773, 0.062471), (-0.022524, 0.158773, 0.057791997), (-0.042466003, 0.158773, 0.044466), (-0.055792, 0.158773, 0.024524), (-0.060471002, 0.158773, 0.001), (-0.055792, 0.158773, -0.022524), (-0.042466003, 0.158773, -0.042466003), (-0.022524, 0.158773, -0.055792), (0.074726, 0.145854, 0.031538), (0.057428, 0.145854, 0.057428), (-0.055428002, 0.145854, 0.057428), (-0.072726, 0.145854, 0.031538), (-0.029538, 0.145854, -0.072726), (0.001, 0.145854, -0.078801), (0.074726, 0.145854, -0.029538), (0.080801, 0.145854, 0.001), (0.001, 0.145854, 0.080801), (-0.029538, 0.145854, 0.074726), (-0.072726, 0.145854, -0.029538), (-0.055428002, 0.145854, -0.055428002), (0.031538, 0.145854, -0.072726), (0.057428, 0.145854, -0.055428002), (0.031538, 0.145854, 0.074726), (-0.078801, 0.145854, 0.001), (0.005965, 0.169715, -0.010987), (0.001, 0.169715, -0.011973999), (0.010174, 0.169715, -0.008173999), (0.012987, 0.169715, -0.0039649997), (0.013974, 0.169715, 0.001), (0.012987, 0.169715, 0.005965), (0.010174, 0.169715, 0.010174), (0.005965, 0.169715, 0.012987), (0.001, 0.169715, 0.013974), (-0.0039649997, 0.169715, 0.012987), (-0.008173999, 0.169715, 0.010174), (-0.010987, 0.169715, 0.005965), (-0.011973999, 0.169715, 0.001), (-0.010987, 0.169715, -0.0039649997), (-0.008173999, 0.169715, -0.008173999), (-0.0039649997, 0.169715, -0.010987), (0.008343, 0.17664701, -0.016727), (0.001, 0.17664701, -0.018188), (0.014568, 0.17664701, -0.012568), (0.018727, 0.17664701, -0.0063429996), (0.020188, 0.17664701, 0.001), (0.018727, 0.17664701, 0.008343), (0.014568, 0.17664701, 0.014568), (0.008343, 0.17664701, 0.018727), (0.001, 0.17664701, 0.020188), (-0.0063429996, 0.17664701, 0.018727), (-0.012568, 0.17664701, 0.014568), (-0.016727, 0.17664701, 0.008343), (-0.018188, 0.17664701, 0.001), (-0.016727, 0.17664701, -0.0063429996), (-0.012568, 0.17664701, -0.012568), (-0.0063429996, 0.17664701, -0.016727), (0.005467, 0.208735, -0.009784), (0.001, 0.209351, -0.009261), (0.008255, 0.209351, -0.006255), (0.011784, 0.208735, -0.0034669996), (0.011261, 0.209351, 0.001), (0.011784, 0.208735, 0.005467), (0.008255, 0.209351, 0.008255), (0.005467, 0.208735, 0.011784), (0.001, 0.209351, 0.011261), (-0.0034669996, 0.208735, 0.011784), (-0.006255, 0.209351, 0.008255), (-0.009784, 0.208735, 0.005467), (-0.009261, 0.209351, 0.001), (-0.009784, 0.208735, -0.0034669996), (-0.006255, 0.209351, -0.006255), (-0.0034669996, 0.208735, -0.009784), (0.009796, 0.18574001, -0.020236), (0.017253, 0.18574001, -0.015253), (-0.0077959998, 0.18574001, 0.022236), (-0.015253, 0.18574001, 0.017253), (0.022236, 0.18574001, -0.0077959998), (-0.020236, 0.18574001, 0.009796), (0.023986, 0.18574001, 0.001), (-0.021986, 0.18574001, 0.001), (0.022236, 0.18574001, 0.009796), (-0.020236, 0.18574001, -0.0077959998), (0.017253, 0.18574001, 0.017253), (-0.015253, 0.18574001, -0.015253), (0.009796, 0.18574001, 0.022236), (-0.0077959998, 0.18574001, -0.020236), (0.001, 0.18574001, 0.023986), (0.001, 0.18574001, -0.021986), (0.001, 0.085413, -0.071244), (0.001, 0.042979, -0.053328), (0.02179, 0.042979, -0.049193002), (0.028647, 0.085413, -0.065744996), (0.039415997, 0.042979, -0.037416), (0.052084, 0.085413, -0.050084002), (0.051193, 0.042979, -0.01979), (0.067745, 0.085413, -0.026647), (0.055327997, 0.042979, 0.001), (0.073244005, 0.085413, 0.001), (0.051193, 0.042979, 0.02179), (0.067745, 0.085413, 0.028647), (0.039415997, 0.042979, 0.039415997), (0.052084, 0.085413, 0.052084), (0.02179, 0.042979, 0.051193), (0.028647, 0.085413, 0.067745), (0.001, 0.042979, 0.055327997), (0.001, 0.085413, 0.073244005), (-0.01979, 0.042979, 0.051193), (-0.026647, 0.085413, 0.067745), (-0.037416, 0.042979, 0.039415997), (-0.050084002, 0.085413, 0.052084), (-0.049193002, 0.042979, 0.02179), (-0.065744996, 0.085413, 0.028647), (-0.053328, 0.042979, 0.001), (-0.071244, 0.085413, 0.001), (-0.049193002, 0.042979, -0.01979), (-0.065744996, 0.085413, -0.026647), (-0.037416, 0.042979, -0.037416), (-0.050084002, 0.085413, -0.050084002), (-0.01979, 0.042979, -0.049193002), (-0.026647, 0.085413, -0.065744996), (0.059288, 0.15781601, 0.025144), (-0.043612003, 0.15781601, 0.045612), (-0.023144, 0.15781601, -0.057288002), (0.059288, 0.15781601, -0.023144), (0.001, 0.15781601, 0.064091004), (-0.057288002, 0.15781601, -0.023144), (0.025144, 0.15781601, -0.057288002), (0.045612, 0.15781601, 0.045612), (-0.057288002, 0.15781601, 0.025144), (0.001, 0.15781601, -0.062091004), (0.064091004, 0.15781601, 0.001), (-0.023144, 0.15781601, 0.059288), (-0.043612003, 0.15781601, -0.043612003), (0.045612, 0.15781601, -0.043612003), (0.025144, 0.15781601, 0.059288), (-0.062091004, 0.15781601, 0.001), (0.057798997, 0.156409, 0.024527), (-0.042472, 0.156409, 0.044471998), (-0.022527, 0.156409, -0.055799), (0.057798997, 0.156409, -0.022527), (0.001, 0.156409, 0.062478997), (-0.055799, 0.156409, -0.022527), (0.024527, 0.156409, -0.055799), (0.044471998, 0.156409, 0.044471998), (-0.055799, 0.156409, 0.024527), (0.001, 0.156409, -0.060479), (0.062478997, 0.156409, 0.001), (-0.022527, 0.156409, 0.057798997), (-0.042472, 0.156409, -0.042472), (0.044471998, 0.156409, -0.042472), (0.024527, 0.156409, 0.057798997), (-0.060479, 0.156409, 0.001), (0.0076630004, 0.204329, -0.015085001), (0.001, 0.204329, -0.01641), (0.013311001, 0.204329, -0.011311), (0.017085, 0.204329, -0.005663), (0.018410001, 0.204329, 0.001), (0.017085, 0.204329, 0.0076630004), (0.013311001, 0.204329, 0.013311001), (0.0076630004, 0.204329, 0.017085), (0.001, 0.204329, 0.018410001), (-0.005663, 0.204329, 0.017085), (-0.011311, 0.204329, 0.013311001), (-0.015085001, 0.204329, 0.0076630004), (-0.01641, 0.204329, 0.001), (-0.015085001, 0.204329, -0.005663), (-0.011311, 0.204329, -0.011311), (-0.005663, 0.204329, -0.015085001), (0.001, 0.21167201, 0.001), (0.009586, 0.196015, -0.019728), (-0.0075859996, 0.196015, 0.021728), (0.001, 0.196015, -0.021436), (0.016865, 0.196015, -0.014865), (-0.014865, 0.196015, 0.016865), (0.021728, 0.196015, -0.0075859996), (-0.019728, 0.196015, 0.009586), (0.023436, 0.196015, 0.001), (-0.021436, 0.196015, 0.001), (0.021728, 0.196015, 0.009586), (-0.019728, 0.196015, -0.0075859996), (0.016865, 0.196015, 0.016865), (-0.014865, 0.196015, -0.014865), (0.009586, 0.196015, 0.021728), (-0.0075859996, 0.196015, -0.019728), (0.001, 0.196015, 0.023436), (0.009881, 0.006556, -0.043649003), (0.019192, 0.125506, -0.090458), (0.026291, 0.006556, -0.036851), (0.052807, 0.125506, -0.076533996), (0.038850997, 0.006556, -0.024291), (0.078534, 0.125506, -0.050807003), (0.045649, 0.006556, -0.007881), (0.092458, 0.125506, -0.017192), (0.045649, 0.006556, 0.009881), (0.092458, 0.125506, 0.019192), (0.038850997, 0.006556, 0.026291), (0.078534, 0.125506, 0.052807), (0.026291, 0.006556, 0.038850997), (0.052807, 0.125506, 0.078534), (0.009881, 0.006556, 0.045649), (0.019192, 0.125506, 0.092458), (-0.007881, 0.006556, 0.045649), (-0.017192, 0.125506, 0.092458), (-0.024291, 0.006556, 0.038850997), (-0.050807003, 0.125506, 0.078534), (-0.036851, 0.006556, 0.026291), (-0.076533996, 0.125506, 0.052807), (-0.043649003, 0.006556, 0.009881), (-0.090458, 0.125506, 0.019192), (-0.043649003, 0.006556, -0.007881), (-0.090458, 0.125506, -0.017192), (-0.036851, 0.006556, -0.024291), (-0.076533996, 0.125506, -0.050807003), (-0.024291, 0.006556, -0.036851), (-0.050807003, 0.125506, -0.076533996), (-0.007881, 0.006556, -0.043649003), (-0.017192, 0.125506, -0.090458), (0.012992, 0.158773, -0.059286002), (0.035148997, 0.158773, -0.050108), (0.052107997, 0.158773, -0.033149), (0.061286, 0.158773, -0.010992), (0.061286, 0.158773, 0.012992), (0.052107997, 0.158773, 0.035148997), (0.035148997, 0.158773, 0.052107997), (0.012992, 0.158773, 0.061286), (-0.010992, 0.158773, 0.061286), (-0.033149, 0.158773, 0.052107997), (-0.050108, 0.158773, 0.035148997), (-0.059286002, 0.158773, 0.012992), (-0.059286002, 0.158773, -0.010992), (-0.050108, 0.158773, -0.033149), (-0.033149, 0.158773, -0.050108), (-0.010992, 0.158773, -0.059286002), (0.057795, 0.157591, 0.024525), (0.062995, 0.13689001, 0.062995), (-0.042469002, 0.157591, 0.044469), (-0.08, 0.13689001, 0.034551), (-0.022525, 0.157591, -0.055795003), (0.001, 0.13689001, -0.086674), (0.057795, 0.157591, -0.022525), (0.088674, 0.13689001, 0.001), (0.001, 0.157591, 0.062475), (-0.032551, 0.13689001, 0.082), (-0.055795003, 0.157591, -0.022525), (-0.060995, 0.13689001, -0.060995), (0.024525, 0.157591, -0.055795003), (0.062995, 0.13689001, -0.060995), (0.034551, 0.13689001, 0.082), (-0.086674, 0.13689001, 0.001), (0.006995, -0.0007819999, -0.029138999), (0.018072, -0.0007819999, -0.02455), (0.02655, -0.0007819999, -0.016072), (0.031139, -0.0007819999, -0.0049949996), (0.031139, -0.0007819999, 0.006995), (0.02655, -0.0007819999, 0.018072), (0.018072, -0.0007819999, 0.02655), (0.006995, -0.0007819999, 0.031139), (-0.0049949996, -0.0007819999, 0.031139), (-0.016072, -0.0007819999, 0.02655), (-0.02455, -0.0007819999, 0.018072), (-0.029138999, -0.0007819999, 0.006995), (-0.029138999, -0.0007819999, -0.0049949996), (-0.02455, -0.0007819999, -0.016072), (-0.016072, -0.0007819999, -0.02455), (-0.0049949996, -0.0007819999, -0.029138999), (0.017061, 0.00013500004, -0.037775002), (0.001, 0.00013500004, -0.04097), (0.030677, 0.00013500004, -0.028677), (0.039775, 0.00013500004, -0.015061), (0.042969998, 0.00013500004, 0.001), (0.039775, 0.00013500004, 0.017061), (0.030677, 0.00013500004, 0.030677), (0.017061, 0.00013500004, 0.039775), (0.001, 0.00013500004, 0.042969998), (-0.015061, 0.00013500004, 0.039775), (-0.028677, 0.00013500004, 0.030677), (-0.037775002, 0.00013500004, 0.017061), (-0.04097, 0.00013500004, 0.001), (-0.037775002, 0.00013500004, -0.015061), (-0.028677, 0.00013500004, -0.028677), (-0.015061, 0.00013500004, -0.037775002), (0.009272, 0.165286, -0.040586002), (0.024557, 0.165286, -0.034255), (0.036255, 0.165286, -0.022557), (0.042586, 0.165286, -0.0072719995), (0.042586, 0.165286, 0.009272), (0.036255, 0.165286, 0.024557), (0.024557, 0.165286, 0.036255), (0.009272, 0.165286, 0.042586), (-0.0072719995, 0.165286, 0.042586), (-0.022557, 0.165286, 0.036255), (-0.034255, 0.165286, 0.024557), (-0.040586002, 0.165286, 0.009272), (-0.040586002, 0.165286, -0.0072719995), (-0.034255, 0.165286, -0.022557), (-0.022557, 0.165286, -0.034255), (-0.0072719995, 0.165286, -0.040586002), (0.001, 0.16222501, -0.051909003), (0.021247, 0.16222501, -0.047881003), (0.038411997, 0.16222501, -0.036412), (0.049881, 0.16222501, -0.019247), (0.053909, 0.16222501, 0.001), (0.049881, 0.16222501, 0.021247), (0.038411997, 0.16222501, 0.038411997), (0.021247, 0.16222501, 0.049881), (0.001, 0.16222501, 0.053909), (-0.019247, 0.16222501, 0.049881), (-0.036412, 0.16222501, 0.038411997), (-0.047881003, 0.16222501, 0.021247), (-0.051909003, 0.16222501, 0.001), (-0.047881003, 0.16222501, -0.019247), (-0.036412, 0.16222501, -0.036412), (-0.019247, 0.16222501, -0.047881003), (0.082, 0.13689001, 0.034551), (0.051597998, 0.152523, 0.051597998), (-0.060995, 0.13689001, 0.062995), (-0.06511, 0.152523, 0.028384), (-0.032551, 0.13689001, -0.08), (0.001, 0.152523, -0.070557), (0.082, 0.13689001, -0.032551), (0.072557, 0.152523, 0.001), (0.001, 0.13689001, 0.088674), (-0.026384, 0.152523, 0.06711), (-0.08, 0.13689001, -0.032551), (-0.049598, 0.152523, -0.049598), (0.034551, 0.13689001, -0.08), (0.051597998, 0.152523, -0.049598), (0.028384, 0.152523, 0.06711), (-0.070557, 0.152523, 0.001), (-0.077262, 0.145854, -0.014567), (0.016567, 0.145854, -0.077262), (0.016567, 0.145854, 0.079262), (0.067348, 0.145854, -0.043332003), (-0.043332003, 0.145854, -0.065348), (-0.043332003, 0.145854, 0.067348), (0.079262, 0.145854, 0.016567), (-0.077262, 0.145854, 0.016567), (0.045332, 0.145854, 0.067348), (0.045332, 0.145854, -0.065348), (-0.065348, 0.145854, -0.043332003), (-0.014567, 0.145854, 0.079262), (0.079262, 0.145854, -0.014567), (-0.014567, 0.145854, -0.077262), (-0.065348, 0.145854, 0.045332), (0.067348, 0.145854, 0.045332), (0.003531, 0.169715, -0.011724), (0.008208, 0.169715, -0.009787), (0.011787, 0.169715, -0.006208), (0.013724, 0.169715, -0.0015309999), (0.013724, 0.169715, 0.003531), (0.011787, 0.169715, 0.008208), (0.008208, 0.169715, 0.011787), (0.003531, 0.169715, 0.013724), (-0.0015309999, 0.169715, 0.013724), (-0.006208, 0.169715, 0.011787), (-0.009787, 0.169715, 0.008208), (-0.011724, 0.169715, 0.003531), (-0.011724, 0.169715, -0.0015309999), (-0.009787, 0.169715, -0.006208), (-0.006208, 0.169715, -0.009787), (-0.0015309999, 0.169715, -0.011724), (0.027479, 0.167696, -0.0099679995), (0.02966, 0.167696, 0.001), (-0.025479, 0.167696, 0.011968), (-0.02766, 0.167696, 0.001), (0.027479, 0.167696, 0.011968), (-0.025479, 0.167696, -0.0099679995), (0.021266, 0.167696, 0.021266), (-0.019266, 0.167696, -0.019266), (0.011968, 0.167696, 0.027479), (-0.0099679995, 0.167696, -0.025479), (0.001, 0.167696, 0.02966), (0.001, 0.167696, -0.02766), (0.011968, 0.167696, -0.025479), (-0.0099679995, 0.167696, 0.027479), (0.021266, 0.167696, -0.019266), (-0.019266, 0.167696, 0.021266), (0.004743, 0.17664701, -0.017818), (0.011659, 0.17664701, -0.0149530005), (0.016953, 0.17664701, -0.009659), (0.019818, 0.17664701, -0.0027430002), (0.019818, 0.17664701, 0.004743), (0.016953, 0.17664701, 0.011659), (0.011659, 0.17664701, 0.016953), (0.004743, 0.17664701, 0.019818), (-0.0027430002, 0.17664701, 0.019818), (-0.009659, 0.17664701, 0.016953), (-0.0149530005, 0.17664701, 0.011659), (-0.017818, 0.17664701, 0.004743), (-0.017818, 0.17664701, -0.0027430002), (-0.0149530005, 0.17664701, -0.009659), (-0.009659, 0.17664701, -0.0149530005), (-0.0027430002, 0.17664701, -0.017818), (-0.013999, 0.173005, 0.007213), (-0.015234999, 0.173005, 0.001), (0.017235, 0.173005, 0.001), (0.015999, 0.173005, 0.007213), (-0.013999, 0.173005, -0.005213), (0.01248, 0.173005, 0.01248), (-0.01048, 0.173005, -0.01048), (0.007213, 0.173005, 0.015999), (-0.005213, 0.173005, -0.013999), (0.001, 0.173005, 0.017235), (0.001, 0.173005, -0.015234999), (0.007213, 0.173005, -0.013999), (-0.005213, 0.173005, 0.015999), (0.01248, 0.173005, -0.01048), (-0.01048, 0.173005, 0.01248), (0.015999, 0.173005, -0.005213), (0.004396, 0.204329, -0.016075), (0.010672, 0.204329, -0.013475), (0.015475, 0.204329, -0.008672), (0.018075, 0.204329, -0.002396), (0.018075, 0.204329, 0.004396), (0.015475, 0.204329, 0.010672), (0.010672, 0.204329, 0.015475), (0.004396, 0.204329, 0.018075), (-0.002396, 0.204329, 0.018075), (-0.008672, 0.204329, 0.015475), (-0.013475, 0.204329, 0.010672), (-0.016075, 0.204329, 0.004396), (-0.016075, 0.204329, -0.002396), (-0.013475, 0.204329, -0.008672), (-0.008672, 0.204329, -0.013475), (-0.002396, 0.204329, -0.016075), (0.008789, 0.20055, -0.017805), (0.016224, 0.180912, -0.014224), (-0.006789, 0.20055, 0.019805001), (-0.014224, 0.180912, 0.016224), (0.020891, 0.180912, -0.007239), (-0.018891, 0.180912, 0.009239), (0.02253, 0.180912, 0.001), (-0.02053, 0.180912, 0.001), (0.020891, 0.180912, 0.009239), (-0.018891, 0.180912, -0.007239), (0.016224, 0.180912, 0.016224), (-0.014224, 0.180912, -0.014224), (0.009239, 0.180912, 0.020891), (-0.007239, 0.180912, -0.018891), (0.001, 0.180912, 0.02253), (0.001, 0.20055, -0.019354999), (0.009239, 0.180912, -0.018891), (0.017489, 0.190927, -0.015488999), (-0.007239, 0.180912, 0.020891), (-0.015488999, 0.190927, 0.017489), (0.022544, 0.190927, -0.007924), (-0.020544, 0.190927, 0.009924), (0.024319, 0.190927, 0.001), (-0.022319, 0.190927, 0.001), (0.022544, 0.190927, 0.009924), (-0.020544, 0.190927, -0.007924), (0.017489, 0.190927, 0.017489), (-0.015488999, 0.190927, -0.015488999), (0.009924, 0.190927, 0.022544), (-0.007924, 0.190927, -0.020544), (0.001, 0.190927, 0.024319), (0.001, 0.180912, -0.02053), (-0.003484, 0.18574001, 0.023543), (-0.003484, 0.18574001, -0.021543), (0.0054840003, 0.18574001, -0.021543), (0.0054840003, 0.18574001, 0.023543), (-0.0117689995, 0.18574001, -0.018111), (0.013769, 0.18574001, 0.020111), (-0.018111, 0.18574001, -0.0117689995), (0.020111, 0.18574001, 0.013769), (-0.021543, 0.18574001, -0.003484), (0.023543, 0.18574001, 0.0054840003), (-0.021543, 0.18574001, 0.0054840003), (0.023543, 0.18574001, -0.003484), (-0.018111, 0.18574001, 0.013769), (0.020111, 0.18574001, -0.0117689995), (-0.0117689995, 0.18574001, 0.020111), (0.013769, 0.18574001, -0.018111), (-0.022824, 0.064393, -0.056515), (-0.013093, 0.085413, -0.069851995), (0.001, 0.064393, -0.061254002), (-0.009598, 0.042979, -0.052281003), (-0.043020003, 0.064393, -0.043020003), (-0.039134003, 0.085413, -0.059065003), (-0.029181, 0.042979, -0.044169), (-0.056515, 0.064393, -0.022824), (-0.059065003, 0.085413, -0.039134003), (-0.044169, 0.042979, -0.029181), (-0.061254002, 0.064393, 0.001), (-0.069851995, 0.085413, -0.013093), (-0.052281003, 0.042979, -0.009598), (-0.056515, 0.064393, 0.024824), (-0.069851995, 0.085413, 0.015093001), (-0.052281003, 0.042979, 0.011598), (-0.043020003, 0.064393, 0.04502), (-0.059065003, 0.085413, 0.041134), (-0.044169, 0.042979, 0.031181), (-0.022824, 0.064393, 0.058514997), (-0.039134003, 0.085413, 0.061065), (-0.029181, 0.042979, 0.046168998), (0.001, 0.064393, 0.063254), (-0.013093, 0.085413, 0.071852), (-0.009598, 0.042979, 0.054281), (0.024824, 0.064393, 0.058514997), (0.015093001, 0.085413, 0.071852), (0.011598, 0.042979, 0.054281), (0.04502, 0.064393, 0.04502), (0.041134, 0.085413, 0.061065), (0.031181, 0.042979, 0.046168998), (0.058514997, 0.064393, 0.024824), (0.061065, 0.085413, 0.041134), (0.046168998, 0.042979, 0.031181), (0.063254, 0.064393, 0.001), (0.071852, 0.085413, 0.015093001), (0.054281, 0.042979, 0.011598), (0.058514997, 0.064393, -0.022824), (0.071852, 0.085413, -0.013093), (0.054281, 0.042979, -0.009598), (0.04502, 0.064393, -0.043020003), (0.061065, 0.085413, -0.039134003), (0.046168998, 0.042979, -0.029181), (0.024824, 0.064393, -0.056515), (0.041134, 0.085413, -0.059065003), (0.031181, 0.042979, -0.044169), (0.015093001, 0.085413, -0.069851995), (0.011598, 0.042979, -0.052281003), (-0.030948, 0.106078, -0.076128), (0.001, 0.106078, -0.082483), (0.001, 0.022116, -0.048003003), (0.019753, 0.022116, -0.044273), (0.032948, 0.106078, -0.076128), (-0.017753, 0.022116, -0.044273), (0.03565, 0.022116, -0.033650003), (0.060030997, 0.106078, -0.058031), (0.046272997, 0.022116, -0.017753), (0.078128, 0.106078, -0.030948), (0.050003, 0.022116, 0.001), (0.084483005, 0.106078, 0.001), (0.046272997, 0.022116, 0.019753), (0.078128, 0.106078, 0.032948), (0.03565, 0.022116, 0.03565), (0.060030997, 0.106078, 0.060030997), (0.019753, 0.022116, 0.046272997), (0.032948, 0.106078, 0.078128), (0.001, 0.022116, 0.050003), (0.001, 0.106078, 0.084483005), (-0.017753, 0.022116, 0.046272997), (-0.030948, 0.106078, 0.078128), (-0.033650003, 0.022116, 0.03565), (-0.058031, 0.106078, 0.060030997), (-0.044273, 0.022116, 0.019753), (-0.076128, 0.106078, 0.032948), (-0.048003003, 0.022116, 0.001), (-0.082483, 0.106078, 0.001), (-0.044273, 0.022116, -0.017753), (-0.076128, 0.106078, -0.030948), (-0.033650003, 0.022116, -0.033650003), (-0.058031, 0.106078, -0.058031), (0.06711, 0.152523, 0.028384), (-0.049598, 0.152523, 0.051597998), (-0.026384, 0.152523, -0.06511), (0.06711, 0.152523, -0.026384), (0.001, 0.152523, 0.072557), (-0.06511, 0.152523, -0.026384), (0.028384, 0.152523, -0.06511), (0.045041997, 0.157112, 0.045041997), (-0.056543, 0.157112, 0.024835), (0.001, 0.157112, -0.061285), (0.063285, 0.157112, 0.001), (-0.022835, 0.157112, 0.058542997), (-0.043042, 0.157112, -0.043042), (0.045041997, 0.157112, -0.043042), (0.024835, 0.157112, 0.058542997), (-0.061285, 0.157112, 0.001), (-0.060874, 0.15781601, -0.0113079995), (0.013308, 0.15781601, -0.060874), (0.013308, 0.15781601, 0.062874), (0.053455, 0.15781601, -0.034049), (-0.034049, 0.15781601, -0.051455002), (-0.034049, 0.15781601, 0.053455), (0.062874, 0.15781601, 0.013308), (-0.060874, 0.15781601, 0.013308), (0.036048997, 0.15781601, 0.053455), (0.036048997, 0.15781601, -0.051455002), (-0.051455002, 0.15781601, -0.034049), (-0.0113079995, 0.15781601, 0.062874), (0.062874, 0.15781601, -0.0113079995), (-0.0113079995, 0.15781601, -0.060874), (-0.051455002, 0.15781601, 0.036048997), (0.053455, 0.15781601, 0.036048997), (0.058544, 0.157112, 0.024835), (-0.043042, 0.157112, 0.045041997), (-0.022835, 0.157112, -0.056543), (0.058544, 0.157112, -0.022835), (0.001, 0.157112, 0.063285), (-0.056543, 0.157112, -0.022835), (0.024835, 0.157112, -0.056543), (0.044469, 0.157591, 0.044469), (-0.055795003, 0.157591, 0.024525), (0.001, 0.157591, -0.060475003), (0.062475, 0.157591, 0.001), (-0.022525, 0.157591, 0.057795), (-0.042469002, 0.157591, -0.042469002), (0.044469, 0.157591, -0.042469002), (0.024525, 0.157591, 0.057795), (-0.060475003, 0.157591, 0.001), (-0.059293002, 0.156409, -0.010993), (0.012993, 0.156409, -0.059293002), (0.012993, 0.156409, 0.061293), (0.052114, 0.156409, -0.033153), (-0.033153, 0.156409, -0.050114002), (-0.033153, 0.156409, 0.052114), (0.061293, 0.156409, 0.012993), (-0.059293002, 0.156409, 0.012993), (0.035152998, 0.156409, 0.052114), (0.035152998, 0.156409, -0.050114002), (-0.050114002, 0.156409, -0.033153), (-0.010993, 0.156409, 0.061293), (0.061293, 0.156409, -0.010993), (-0.010993, 0.156409, -0.059293002), (-0.050114002, 0.156409, 0.035152998), (0.052114, 0.156409, 0.035152998), (0.003505, 0.20919201, -0.009405), (0.0065860003, 0.20919201, -0.008129), (0.010129, 0.20919201, -0.004586), (0.011405, 0.20919201, -0.0015049999), (0.011405, 0.20919201, 0.003505), (0.010129, 0.20919201, 0.0065860003), (0.0065860003, 0.20919201, 0.010129), (0.003505, 0.20919201, 0.011405), (-0.0015049999, 0.20919201, 0.011405), (-0.004586, 0.20919201, 0.010129), (-0.008129, 0.20919201, 0.0065860003), (-0.009405, 0.20919201, 0.003505), (-0.009405, 0.20919201, -0.0015049999), (-0.008129, 0.20919201, -0.004586), (-0.004586, 0.20919201, -0.008129), (-0.0015049999, 0.20919201, -0.009405), (0.001, 0.207247, -0.012976999), (0.0064100004, 0.207172, -0.012062), (0.010883, 0.207247, -0.008882999), (0.014062, 0.207172, -0.00441), (0.014977, 0.207247, 0.001), (0.014062, 0.207172, 0.0064100004), (0.010883, 0.207247, 0.010883), (0.0064100004, 0.207172, 0.014062), (0.001, 0.207247, 0.014977), (-0.00441, 0.207172, 0.014062), (-0.008882999, 0.207247, 0.010883), (-0.012062, 0.207172, 0.0064100004), (-0.012976999, 0.207247, 0.001), (-0.012062, 0.207172, -0.00441), (-0.008882999, 0.207247, -0.008882999), (-0.00441, 0.207172, -0.012062), (0.001, 0.210761, -0.005234), (-0.0034079999, 0.210761, -0.0034079999), (-0.005234, 0.210761, 0.001), (-0.0034079999, 0.210761, 0.005408), (0.001, 0.210761, 0.007234), (0.005408, 0.210761, 0.005408), (0.007234, 0.210761, 0.001), (0.005408, 0.210761, -0.0034079999), (0.009924, 0.190927, -0.020544), (-0.007924, 0.190927, 0.022544), (0.001, 0.190927, -0.022319), (0.015393, 0.20055, -0.013393), (-0.013393, 0.20055, 0.015393), (0.019805001, 0.20055, -0.006789), (-0.017805, 0.20055, 0.008789), (0.021355, 0.20055, 0.001), (-0.019354999, 0.20055, 0.001), (0.019805001, 0.20055, 0.008789), (-0.017805, 0.20055, -0.006789), (0.015393, 0.20055, 0.015393), (-0.013393, 0.20055, -0.013393), (0.008789, 0.20055, 0.019805001), (-0.006789, 0.20055, -0.017805), (0.001, 0.20055, 0.021355), (-0.0033769999, 0.196015, 0.023004001), (-0.0033769999, 0.196015, -0.021004), (0.005377, 0.196015, -0.021004), (0.005377, 0.196015, 0.023004001), (-0.011464, 0.196015, -0.017654), (0.013464, 0.196015, 0.019654), (-0.017654, 0.196015, -0.011464), (0.019654, 0.196015, 0.013464), (-0.021004, 0.196015, -0.0033769999), (0.023004001, 0.196015, 0.005377), (-0.021004, 0.196015, 0.005377), (0.023004001, 0.196015, -0.0033769999), (-0.017654, 0.196015, 0.013464), (0.019654, 0.196015, -0.011464), (-0.011464, 0.196015, 0.019654), (0.013464, 0.196015, -0.017654), (-0.008559, 0.022116, -0.047058), (0.041741997, 0.022116, -0.026223), (0.049057998, 0.022116, -0.008559), (0.049057998, 0.022116, 0.010559), (0.041741997, 0.022116, 0.028223), (0.028223, 0.022116, 0.041741997), (0.010559, 0.022116, 0.049057998), (-0.008559, 0.022116, 0.049057998), (-0.026223, 0.022116, 0.041741997), (-0.039742, 0.022116, 0.028223), (-0.047058, 0.022116, 0.010559), (-0.047058, 0.022116, -0.008559), (-0.039742, 0.022116, -0.026223), (-0.026223, 0.022116, -0.039742), (0.052111, 0.157591, 0.035150997), (0.010559, 0.022116, -0.047058), (0.001, -0.0007819999, 0.001), (0.029108, 0.167696, -0.004591), (-0.050111003, 0.157591, 0.035150997), (-0.010992, 0.157591, -0.059290003), (0.06129, 0.157591, -0.010992), (-0.010992, 0.157591, 0.06129), (-0.050111003, 0.157591, -0.033151), (0.035150997, 0.157591, -0.050111003), (0.035150997, 0.157591, 0.052111), (-0.059290003, 0.157591, 0.012992), (0.06129, 0.157591, 0.012992), (-0.033151, 0.157591, 0.052111), (-0.033151, 0.157591, -0.050111003), (0.052111, 0.157591, -0.033151), (0.012992, 0.157591, 0.06129), (0.012992, 0.157591, -0.059290003), (-0.059290003, 0.157591, -0.010992), (0.009124001, 0.00013500004, -0.039841), (0.024134, 0.00013500004, -0.033623002), (0.035623, 0.00013500004, -0.022134), (0.041840997, 0.00013500004, -0.007124), (0.041840997, 0.00013500004, 0.009124001), (0.035623, 0.00013500004, 0.024134), (0.024134, 0.00013500004, 0.035623), (0.009124001, 0.00013500004, 0.041840997), (-0.007124, 0.00013500004, 0.041840997), (-0.022134, 0.00013500004, 0.035623), (-0.033623002, 0.00013500004, 0.024134), (-0.039841, 0.00013500004, 0.009124001), (-0.039841, 0.00013500004, -0.007124), (-0.033623002, 0.00013500004, -0.022134), (-0.022134, 0.00013500004, -0.033623002), (-0.007124, 0.00013500004, -0.039841), (0.011321, 0.16222501, -0.050889), (0.030393, 0.16222501, -0.042989), (0.044988997, 0.16222501, -0.028393), (0.052888997, 0.16222501, -0.0093209995), (0.052888997, 0.16222501, 0.011321), (0.044988997, 0.16222501, 0.030393), (0.030393, 0.16222501, 0.044988997), (0.011321, 0.16222501, 0.052888997), (-0.0093209995, 0.16222501, 0.052888997), (-0.028393, 0.16222501, 0.044988997), (-0.042989, 0.16222501, 0.030393), (-0.050889, 0.16222501, 0.011321), (-0.050889, 0.16222501, -0.0093209995), (-0.042989, 0.16222501, -0.028393), (-0.028393, 0.16222501, -0.042989), (-0.0093209995, 0.16222501, -0.050889), (0.028223, 0.022116, -0.039742), (-0.045378003, 0.106078, -0.068408996), (-0.068408996, 0.106078, -0.045378003), (-0.080873996, 0.106078, -0.015286), (-0.080873996, 0.106078, 0.017286), (-0.068408996, 0.106078, 0.047378), (-0.045378003, 0.106078, 0.070409), (-0.015286, 0.106078, 0.082874), (0.017286, 0.106078, 0.082874), (0.047378, 0.106078, 0.070409), (0.070409, 0.106078, 0.047378), (0.082874, 0.106078, 0.017286), (0.082874, 0.106078, -0.015286), (0.070409, 0.106078, -0.045378003), (0.047378, 0.106078, -0.068408996), (0.017286, 0.106078, -0.080873996), (-0.084984, 0.13689001, -0.016103), (0.018103, 0.13689001, -0.084984), (0.018103, 0.13689001, 0.086984), (0.073893, 0.13689001, -0.047706), (-0.047706, 0.13689001, -0.071893), (-0.047706, 0.13689001, 0.073893), (0.086984, 0.13689001, 0.018103), (-0.084984, 0.13689001, 0.018103), (0.049705997, 0.13689001, 0.073893), (0.049705997, 0.13689001, -0.071893), (-0.071893, 0.13689001, -0.047706), (-0.016103, 0.13689001, 0.086984), (0.086984, 0.13689001, -0.016103), (-0.016103, 0.13689001, -0.084984), (-0.071893, 0.13689001, 0.049705997), (0.073893, 0.13689001, 0.049705997), (-0.0149220005, 0.173005, 0.004167), (-0.027108, 0.167696, 0.006591), (0.029108, 0.167696, 0.006591), (-0.027108, 0.167696, -0.004591), (0.024829, 0.167696, 0.016922), (-0.022829, 0.167696, -0.0149220005), (0.016922, 0.167696, 0.024829), (-0.0149220005, 0.167696, -0.022829), (0.006591, 0.167696, 0.029108), (0.006591, 0.167696, -0.027108), (-0.004591, 0.167696, -0.027108), (-0.004591, 0.167696, 0.029108), (0.016922, 0.167696, -0.022829), (-0.0149220005, 0.167696, 0.024829), (0.024829, 0.167696, -0.0149220005), (-0.022829, 0.167696, 0.016922), (0.012308001, 0.20055, -0.015922999), (0.016922, 0.173005, 0.004167), (-0.0149220005, 0.173005, -0.002167), (0.014498, 0.173005, 0.010019001), (-0.012498, 0.173005, -0.008019), (0.010019001, 0.173005, 0.014498), (-0.008019, 0.173005, -0.012498), (0.004167, 0.173005, 0.016922), (0.004167, 0.173005, -0.0149220005), (-0.002167, 0.173005, -0.0149220005), (-0.002167, 0.173005, 0.016922), (0.010019001, 0.173005, -0.012498), (-0.008019, 0.173005, 0.014498), (0.014498, 0.173005, -0.008019), (-0.012498, 0.173005, 0.010019001), (0.016922, 0.173005, -0.002167), (-0.0035489998, 0.190927, 0.02387), (-0.010308, 0.20055, 0.017923), (0.017923, 0.20055, -0.010308), (-0.015922999, 0.20055, 0.012308001), (0.020962, 0.20055, -0.002971), (-0.018962, 0.20055, 0.0049710004), (0.020962, 0.20055, 0.0049710004), (-0.018962, 0.20055, -0.002971), (0.017923, 0.20055, 0.012308001), (-0.015922999, 0.20055, -0.010308), (0.012308001, 0.20055, 0.017923), (-0.010308, 0.20055, -0.015922999), (0.0049710004, 0.20055, 0.020962), (0.0049710004, 0.20055, -0.018962), (-0.002971, 0.20055, -0.018962), (-0.002971, 0.20055, 0.020962), (-0.0031999997, 0.180912, 0.022115), (-0.0031999997, 0.180912, -0.020115), (0.0052, 0.180912, -0.020115), (0.0052, 0.180912, 0.022115), (-0.010961, 0.180912, -0.016901), (0.012961, 0.180912, 0.018901), (-0.0169, 0.180912, -0.010961), (0.018901, 0.180912, 0.012961), (-0.020115, 0.180912, -0.0031999997), (0.022115, 0.180912, 0.0052), (-0.020115, 0.180912, 0.0052), (0.022115, 0.180912, -0.0031999997), (-0.0169, 0.180912, 0.012961), (0.018901, 0.180912, -0.010961), (-0.010961, 0.180912, 0.018901), (0.012961, 0.180912, -0.016901), (-0.011144, 0.064393, -0.060054), (-0.033584002, 0.064393, -0.050759003), (-0.050759003, 0.064393, -0.033584002), (-0.060054, 0.064393, -0.011144), (-0.060054, 0.064393, 0.013144), (-0.050759003, 0.064393, 0.035584), (-0.033584002, 0.064393, 0.052759), (-0.011144, 0.064393, 0.062053997), (0.013144, 0.064393, 0.062053997), (0.035584, 0.064393, 0.052759), (0.052759, 0.064393, 0.035584), (0.062053997, 0.064393, 0.013144), (0.062053997, 0.064393, -0.011144), (0.052759, 0.064393, -0.033584002), (0.035584, 0.064393, -0.050759003), (0.013144, 0.064393, -0.060054), (-0.015286, 0.106078, -0.080873996), (-0.069176994, 0.152523, -0.012959), (0.014959, 0.152523, -0.069176994), (0.014959, 0.152523, 0.071177), (0.060493, 0.152523, -0.038752), (-0.038752, 0.152523, -0.058494), (-0.038752, 0.152523, 0.060494), (0.071177, 0.152523, 0.014959), (-0.069176994, 0.152523, 0.014959), (0.040751997, 0.152523, 0.060494), (0.040751997, 0.152523, -0.058494), (-0.058493003, 0.152523, -0.038752), (-0.012959, 0.152523, 0.071177), (0.071177, 0.152523, -0.012959), (-0.012959, 0.152523, -0.069176994), (-0.058493003, 0.152523, 0.040751997), (0.060494, 0.152523, 0.040751997), (-0.060084, 0.157112, -0.01115), (0.01315, 0.157112, -0.060084), (0.01315, 0.157112, 0.062083997), (0.052784, 0.157112, -0.033601), (-0.033601, 0.157112, -0.050784003), (-0.033601, 0.157112, 0.052784), (0.062083997, 0.157112, 0.01315), (-0.060084, 0.157112, 0.01315), (0.035600998, 0.157112, 0.052784), (0.035600998, 0.157112, -0.050784003), (-0.050784003, 0.157112, -0.033601), (-0.01115, 0.157112, 0.062083997), (0.062083997, 0.157112, -0.01115), (-0.01115, 0.157112, -0.060084), (-0.050784003, 0.157112, 0.035600998), (0.052784, 0.157112, 0.035600998), (0.003786, 0.207223, -0.012756), (0.008757, 0.207223, -0.010697), (0.012697, 0.207223, -0.006757), (0.014756001, 0.207223, -0.001786), (0.014756001, 0.207223, 0.003786), (0.012697, 0.207223, 0.008757), (0.008757, 0.207223, 0.012697), (0.003786, 0.207223, 0.014756001), (-0.001786, 0.207223, 0.014756001), (-0.006757, 0.207223, 0.012697), (-0.010697, 0.207223, 0.008757), (-0.012756, 0.207223, 0.003786), (-0.012756, 0.207223, -0.001786), (-0.010697, 0.207223, -0.006757), (-0.006757, 0.207223, -0.010697), (-0.001786, 0.207223, -0.012756), (-0.001981, 0.210341, -0.006197), (-0.006197, 0.210341, -0.001981), (-0.006197, 0.210341, 0.0039810003), (-0.001981, 0.210341, 0.008197), (0.0039810003, 0.210341, 0.008197), (0.008197, 0.210341, 0.0039810003), (0.008197, 0.210341, -0.001981), (-0.0035489998, 0.190927, -0.02187), (0.005549, 0.190927, -0.02187), (0.005549, 0.190927, 0.02387), (-0.0119549995, 0.190927, -0.018388), (0.013955, 0.190927, 0.020388), (-0.018388, 0.190927, -0.0119549995), (0.020388, 0.190927, 0.013955), (-0.02187, 0.190927, -0.0035489998), (0.02387, 0.190927, 0.005549), (-0.02187, 0.190927, 0.005549), (0.02387, 0.190927, -0.0035489998), (-0.018388, 0.190927, 0.013955), (0.020388, 0.190927, -0.0119549995), (-0.0119549995, 0.190927, 0.020388), (0.013955, 0.190927, -0.018388), (0.0039810003, 0.210341, -0.006197), (-0.103468, 0.114549, -0.015364999), (-0.099006, 0.123178005, -0.010825), (-0.097153, 0.126874, 0.000705), (-0.099075995, 0.123434, 0.012345), (-0.10357, 0.114909, 0.017147), (-0.107929, 0.106325, 0.012426), (-0.109675, 0.102677, 0.0010660001), (-0.107855, 0.106071, -0.010395), (-0.072354, 0.09465, -0.021831), (-0.066700995, 0.106882, -0.015191), (-0.064422995, 0.112115, 0.0012010001), (-0.066856995, 0.107282005, 0.017744001), (-0.072574995, 0.095216, 0.024747001), (-0.078228995, 0.082983, 0.018108001), (-0.080506, 0.077751, 0.001715), (-0.078072995, 0.082583, -0.014827), (-0.041492, 0.070007004, -0.027205), (-0.034065, 0.085275, -0.018852), (-0.031065999, 0.09181, 0.001771), (-0.034252003, 0.085783005, 0.022582), (-0.041757002, 0.070724, 0.031392), (-0.049185, 0.055455998, 0.023039), (-0.052183002, 0.048920996, 0.002416), (-0.048997, 0.054948, -0.018395), (-0.026994, 0.059409, -0.010013), (-0.023891, 0.065787, -0.006524), (-0.022638999, 0.068517, 0.002091), (-0.02397, 0.065999, 0.010784), (-0.027105, 0.059708, 0.014464), (-0.030207999, 0.053329997, 0.010975), (-0.031460002, 0.050600998, 0.00236), (-0.030129, 0.053117998, -0.006333), (-0.13652, 0.127349, -0.0113389995), (-0.125406, 0.132312, -0.008437), (-0.120481, 0.134512, 0.00061600003), (-0.12512, 0.13244, 0.009776), (-0.136135, 0.12752, 0.012886), (-0.146635, 0.12283, 0.0088990005), (-0.150919, 0.120917, 0.00086000003), (-0.14689499, 0.122714005, -0.0072170002), (-0.101064, 0.119193, -0.014242), (-0.11264, 0.128308, -0.00952), (-0.097635, 0.125863, -0.0055569997), (-0.109578, 0.13128, 0.0006230001), (-0.097672, 0.126002, 0.006995), (-0.112559, 0.1285, 0.010873), (-0.101156995, 0.119527005, 0.015883999), (-0.119591, 0.121707, 0.014813), (-0.105941996, 0.110278, 0.015901), (-0.12632799, 0.114982, 0.01054), (-0.109228, 0.103672005, 0.007208), (-0.12905599, 0.112159, 0.00092500006), (-0.109187, 0.103535004, -0.005103), (-0.126395, 0.114796005, -0.008761), (-0.105845995, 0.109946005, -0.013983999), (-0.069289, 0.101239, -0.020131), (-0.065001994, 0.11068, -0.007681), (-0.065086, 0.110897005, 0.0101230005), (-0.069492996, 0.101762004, 0.022851), (-0.07564, 0.088626005, 0.023048), (-0.079927, 0.079185, 0.010598), (-0.079843, 0.078969, -0.0072059995), (-0.075436994, 0.088104, -0.019935), (-0.092429996, 0.095683, -0.012274), (-0.094285995, 0.091633, 0.0013380001), (-0.082031995, 0.120405, 0.0009120001), (-0.083766, 0.116027005, -0.012598), (-0.092565, 0.096004, 0.014827), (-0.088267, 0.106240004, 0.020304), (-0.088077, 0.105785005, -0.018052), (-0.083900996, 0.116349004, 0.014547), (-0.037466, 0.078231, -0.025067), (-0.03183, 0.090018004, -0.009403), (-0.031932, 0.090292, 0.012995), (-0.037711002, 0.078893, 0.029007), (-0.045783002, 0.0625, 0.029254), (-0.051419, 0.050713, 0.013590001), (-0.051317003, 0.050439, -0.008808), (-0.045538, 0.061837, -0.02482), (-0.048817, 0.096692, 0.02149), (-0.055993002, 0.082221, 0.02995), (-0.048636, 0.096205, -0.018296), (-0.04577, 0.102485, 0.0015070001), (-0.065959, 0.061267998, 0.002126), (-0.062911995, 0.067061, -0.017857), (-0.063093, 0.067547, 0.021929), (-0.055737, 0.081532, -0.026316), (-0.025311999, 0.062844, -0.00912), (-0.022958, 0.067768, -0.0025769998), (-0.023001, 0.067882, 0.006779), (-0.025415, 0.063121, 0.013468), (-0.028787, 0.056273, 0.013571), (-0.031141, 0.051349, 0.007028), (-0.031097999, 0.051234998, -0.002328), (-0.028684, 0.055995997, -0.009017), (-0.041, 0.046635997, 0.002463), (-0.038494002, 0.051376, -0.013902999), (-0.032802, 0.063782, 0.025248), (-0.038642, 0.051774997, 0.018679), (-0.024394, 0.080362, 0.001955), (-0.026899999, 0.075623006, 0.01832), (-0.032593, 0.063217, -0.02083), (-0.026752, 0.075224005, -0.014262), (-0.119696, 0.12144, -0.013228999), (-0.14990799, 0.121369004, -0.0034269998), (-0.149769, 0.121431, 0.0051390002), (-0.141866, 0.124961, 0.011639), (-0.130238, 0.130154, 0.012267), (-0.12168, 0.133976, 0.0056350003), (-0.121837, 0.133906, -0.004373), (-0.130602, 0.129991, -0.0108239995), (-0.142212, 0.124806, -0.010011), (-0.093778, 0.092655, -0.006001), (-0.082471, 0.119206004, -0.00641), (-0.0906, 0.100725, 0.018898001), (-0.090424, 0.100305, -0.016483), (-0.08574, 0.111303, -0.016662), (-0.082543, 0.119380005, 0.008267), (-0.093851, 0.092829004, 0.008646), (-0.085916, 0.111723, 0.01875), (-0.052124, 0.090072, 0.027660001), (-0.0465, 0.100763, -0.009223), (-0.065131, 0.062727, -0.0086509995), (-0.059842, 0.074317, 0.027897), (-0.046598002, 0.101026, 0.012284), (-0.051888, 0.089436, -0.024264), (-0.059605002, 0.073681004, -0.024027), (-0.065229, 0.06299, 0.012856), (-0.040319003, 0.047829997, -0.006364), (-0.035967004, 0.057313997, 0.023566), (-0.025075, 0.079169005, 0.010781), (-0.029427, 0.069685005, -0.019149), (-0.035775002, 0.056792997, -0.018955), (-0.040399, 0.048045997, 0.011249), (-0.02962, 0.070206, 0.023372), (-0.024995, 0.078953005, -0.006832), (-0.128371, 0.112826005, -0.0042629996), (-0.128335, 0.112926, 0.006095), (-0.123265, 0.118066005, 0.013602), (-0.115823, 0.12537299, 0.013862), (-0.110360995, 0.13057001, 0.006192), (-0.110406, 0.130466, -0.004917), (-0.115925, 0.12512499, -0.012399), (-0.123357, 0.117822, -0.011909), (-0.103078, 0.114394, -0.012281), (-0.099734, 0.120954, -0.008808), (-0.098269, 0.124011, 0.000735), (-0.099789, 0.121155, 0.010388), (-0.10316, 0.114654005, 0.014071), (-0.106580995, 0.108235, 0.0104), (-0.108088, 0.105318, 0.001041), (-0.106525995, 0.108037, -0.008412), (-0.071765, 0.0944, -0.018778), (-0.067608, 0.104701005, -0.0132019995), (-0.065781996, 0.109363005, 0.001228), (-0.067729995, 0.10504, 0.015809), (-0.07194, 0.094855, 0.021715), (-0.076307, 0.084512, 0.016247), (-0.078267, 0.079843, 0.0017), (-0.076182, 0.084177, -0.01299), (-0.042110004, 0.070384, -0.024046), (-0.036025003, 0.083724, -0.016912), (-0.033371, 0.089693, 0.0017860001), (-0.036193002, 0.084173, 0.020669), (-0.042354003, 0.070999004, 0.028214), (-0.048247002, 0.057710998, 0.020953), (-0.050756, 0.051758997, 0.002386), (-0.048077002, 0.057258997, -0.016371), (-0.0293, 0.060597, -0.008401), (-0.026757, 0.065765, -0.0054909997), (-0.025647, 0.068071, 0.002083), (-0.026828, 0.065951005, 0.009734), (-0.029411, 0.060845997, 0.012816), (-0.031722, 0.055703998, 0.009796), (-0.032629002, 0.053413, 0.002322), (-0.031637, 0.055528, -0.005221), (-0.13610199, 0.127566, -0.00821), (-0.125876, 0.130195, -0.0062019997), (-0.121349, 0.13156201, 0.0006530001), (-0.125607, 0.13026701, 0.007602), (-0.135732, 0.127657, 0.00975), (-0.14563, 0.125043, 0.006941), (-0.149768, 0.123775005, 0.00083100004), (-0.145888, 0.124970004, -0.0053109997), (-0.101293, 0.117845, -0.0114589995), (-0.113229, 0.126068, -0.007476), (-0.098657, 0.123159, -0.004512), (-0.110527, 0.128355, 0.00065500004), (-0.098685995, 0.123272, 0.006012), (-0.113152996, 0.126209, 0.008889), (-0.101369, 0.118093, 0.013144), (-0.119270995, 0.121493004, 0.011732), (-0.10497, 0.111248, 0.01312), (-0.125278, 0.116985, 0.008437), (-0.107693, 0.106129006, 0.006155), (-0.127805, 0.114973, 0.00089300005), (-0.107664, 0.106018, -0.004098), (-0.125348, 0.11685, -0.006708), (-0.104896, 0.111002006, -0.011228), (-0.069554, 0.099867, -0.017358), (-0.066259, 0.10807, -0.0066629997), (-0.066325, 0.108257, 0.009159001), (-0.069713995, 0.100296006, 0.020123), (-0.074226, 0.089372, 0.020393), (-0.077758, 0.081137, 0.009658), (-0.07769, 0.080952, -0.0062949997), (-0.074064, 0.088946, -0.017288), (-0.090751, 0.097451, -0.010377999), (-0.092282996, 0.093968004, 0.00132), (-0.083289, 0.117604, 0.00094000006), (-0.084607, 0.113824, -0.0106029995), (-0.090854, 0.09771, 0.0129), (-0.087724, 0.105929, 0.017249), (-0.087575994, 0.105583005, -0.014981), (-0.084709994, 0.114086, 0.012609), (-0.038889002, 0.077478, -0.022298), (-0.034059003, 0.088041, -0.008425999), (-0.034149002, 0.088287, 0.012047), (-0.039112, 0.078051, 0.026251), (-0.045513, 0.063923, 0.02636), (-0.050106, 0.053409, 0.01252), (-0.050015002, 0.053159997, -0.0077980002), (-0.045288, 0.063348, -0.021975), (-0.049946003, 0.094571, 0.019537), (-0.055571, 0.081959, 0.026859), (-0.049795, 0.094146, -0.016293), (-0.047383003, 0.099865004, 0.001531), (-0.063785, 0.063434, 0.002109), (-0.061124, 0.068737, -0.01598), (-0.061277002, 0.069161005, 0.020021), (-0.055352002, 0.08138, -0.023214), (-0.027958, 0.06335, -0.0076899994), (-0.025934, 0.067428, -0.0020459997), (-0.025972, 0.06753, 0.006232), (-0.028055, 0.063586004, 0.012015), (-0.030681001, 0.058098, 0.01202), (-0.032397002, 0.05405, 0.006386), (-0.032351002, 0.053953, -0.001761), (-0.030574, 0.057869997, -0.007519), (-0.041258, 0.049822997, 0.002422), (-0.039228003, 0.054029997, -0.012283), (-0.034691002, 0.064720005, 0.022878), (-0.039378002, 0.054383997, 0.016985001), (-0.02731, 0.079295, 0.001955), (-0.029607, 0.075029, 0.016882), (-0.034486003, 0.064227, -0.018494), (-0.029469, 0.074668005, -0.012825999), (-0.119369, 0.121313006, -0.010144), (-0.148789, 0.124089, -0.0024779998), (-0.148651, 0.124131, 0.0041320003), (-0.141095, 0.126293, 0.008896), (-0.1303, 0.129002, 0.009348), (-0.122453, 0.13121, 0.004523), (-0.1226, 0.131166, -0.0031889998), (-0.13064499, 0.12891701, -0.007873), (-0.141434, 0.126203, -0.0073039997), (-0.091857, 0.094863005, -0.005054), (-0.083633, 0.116554, -0.005386), (-0.089352995, 0.101579, 0.016188), (-0.089218, 0.101254, -0.0137869995), (-0.086001, 0.109939, -0.013885), (-0.083688, 0.1167, 0.0073), (-0.091912, 0.095007, 0.0076630004), (-0.086137, 0.110266, 0.016019), (-0.052591003, 0.088716, 0.024895), (-0.048010003, 0.098281, -0.008202), (-0.063051, 0.064786, -0.0077209994), (-0.058584, 0.075153, 0.025179), (-0.048092, 0.098515004, 0.011313), (-0.052391, 0.088174, -0.02146), (-0.058383003, 0.074613005, -0.021325), (-0.063134, 0.065019004, 0.011893), (-0.040697, 0.050892, -0.005533), (-0.037247002, 0.059187, 0.021326), (-0.02794, 0.078212, 0.010050001), (-0.031827003, 0.069792, -0.017121), (-0.037054002, 0.058732, -0.016773), (-0.040778, 0.051085, 0.010339), (-0.032011002, 0.070253, 0.021332), (-0.027867, 0.078015, -0.0061009997), (-0.127169, 0.115455, -0.003203), (-0.127131, 0.115529, 0.004974), (-0.12251, 0.119088, 0.010779), (-0.115995996, 0.123966, 0.01111), (-0.111221, 0.127794, 0.005187), (-0.111263, 0.127713, -0.003848), (-0.11609, 0.123794004, -0.009608), (-0.122600995, 0.118918, -0.009115), (-0.084373996, 0.159373, 0.015202), (-0.080476, 0.153759, 0.015916), (-0.089563, 0.155965, 0.015202), (-0.086392, 0.149874, 0.015916), (-0.084961995, 0.159718, 0.001), (-0.081553996, 0.154529, 0.001), (-0.090151, 0.15631, 0.001), (-0.086743, 0.151121, 0.001), (-0.080423, 0.153356, 0.023891), (-0.082890995, 0.157296, 0.021774), (-0.086783, 0.15474, 0.021774), (-0.085612, 0.149949, 0.023891), (-0.074465, 0.143987, 0.018299), (-0.079659, 0.140576, 0.018299), (-0.073611, 0.143085, 0.024363), (-0.078805, 0.13967401, 0.024363), (-0.082495, 0.144344, 0.024614), (-0.082779, 0.144645, 0.018049), (-0.077156, 0.148338, 0.018049), (-0.076871, 0.148037, 0.024614), (0.086374, 0.159373, 0.015202), (0.082476005, 0.153759, 0.015916), (0.091563, 0.155965, 0.015202), (0.088392004, 0.149874, 0.015916), (0.086962, 0.159718, 0.001), (0.083554, 0.154529, 0.001), (0.092151, 0.15631, 0.001), (0.088743, 0.151121, 0.001), (0.082423, 0.153356, 0.023891), (0.084891, 0.157296, 0.021774), (0.088783, 0.15474, 0.021774), (0.087612, 0.149949, 0.023891), (0.076465, 0.143987, 0.018299), (0.081659004, 0.140576, 0.018299), (0.075611, 0.143085, 0.024363), (0.080805, 0.13967401, 0.024363), (0.084495, 0.144344, 0.024614), (0.084779, 0.144645, 0.018049), (0.079156004, 0.148338, 0.018049), (0.078871004, 0.148037, 0.024614), (-0.084373996, 0.159373, -0.0132019995), (-0.080476, 0.153759, -0.013916), (-0.089563, 0.155965, -0.0132019995), (-0.086392, 0.149874, -0.013916), (-0.080423, 0.153356, -0.021891), (-0.082890995, 0.157296, -0.019774), (-0.086783, 0.15474, -0.019774), (-0.085612, 0.149949, -0.021891), (-0.074465, 0.143987, -0.016299), (-0.079659, 0.140576, -0.016299), (-0.073611, 0.143085, -0.022363), (-0.078805, 0.13967401, -0.022363), (-0.082495, 0.144344, -0.022614), (-0.082779, 0.144645, -0.016049), (-0.077156, 0.148338, -0.016049), (-0.076871, 0.148037, -0.022614), (0.086374, 0.159373, -0.0132019995), (0.082476005, 0.153759, -0.013916), (0.091563, 0.155965, -0.0132019995), (0.088392004, 0.149874, -0.013916), (0.082423, 0.153356, -0.021891), (0.084891, 0.157296, -0.019774), (0.088783, 0.15474, -0.019774), (0.087612, 0.149949, -0.021891), (0.076465, 0.143987, -0.016299), (0.081659004, 0.140576, -0.016299), (0.075611, 0.143085, -0.022363), (0.080805, 0.13967401, -0.022363), (0.084495, 0.144344, -0.022614), (0.084779, 0.144645, -0.016049), (0.079156004, 0.148338, -0.016049), (0.078871004, 0.148037, -0.022614), (-0.081437, 0.154447, 0.008747), (-0.081803, 0.1575, 0.015425), (-0.087519, 0.15859, 0.015132), (-0.082903, 0.150937, 0.014532), (-0.08907, 0.15272701, 0.015425), (-0.090077, 0.156267, 0.008696), (-0.084888, 0.159675, 0.008696), (-0.086704, 0.150988, 0.008747), (-0.08351, 0.151852, 0.001), (-0.088194996, 0.158987, 0.001), (-0.08942, 0.153077, 0.001), (-0.082284994, 0.157762, 0.001), (-0.081581995, 0.15588601, 0.022904), (-0.084773995, 0.156243, 0.021999), (-0.082929, 0.151601, 0.024957), (-0.086549, 0.152624, 0.022904), (-0.08688, 0.14939201, 0.020811), (-0.079611994, 0.154164, 0.020811), (-0.083335996, 0.158557, 0.019578), (-0.088303, 0.155295, 0.019578), (-0.076951, 0.142081, 0.017557), (-0.075821996, 0.140888, 0.025105), (-0.079619996, 0.13936101, 0.021331001), (-0.073153995, 0.143608, 0.021331001), (-0.08057, 0.141168, 0.024907), (-0.084318995, 0.147301, 0.01802), (-0.078998, 0.150796, 0.01802), (-0.074443996, 0.14519, 0.024907), (-0.08421, 0.147294, 0.024333), (-0.08133, 0.141971, 0.017755), (-0.075203, 0.14599401, 0.017755), (-0.078967, 0.150737, 0.024333), (-0.075959, 0.14888, 0.021331001), (-0.079629995, 0.146134, 0.025844), (-0.080021, 0.146548, 0.016818), (-0.083691, 0.143802, 0.021331001), (0.083437, 0.154447, 0.008747), (0.083803006, 0.1575, 0.015425), (0.089519, 0.15859, 0.015132), (0.084903, 0.150937, 0.014532), (0.091070004, 0.15272701, 0.015425), (0.092077, 0.156267, 0.008696), (0.086888, 0.159675, 0.008696), (0.088704005, 0.150988, 0.008747), (0.08551, 0.151852, 0.001), (0.090195, 0.158987, 0.001), (0.09142, 0.153077, 0.001), (0.084285, 0.157762, 0.001), (0.083582, 0.15588601, 0.022904), (0.086774, 0.156243, 0.021999), (0.084929004, 0.151601, 0.024957), (0.088549, 0.152624, 0.022904), (0.08888, 0.14939201, 0.020811), (0.081612, 0.154164, 0.020811), (0.085336, 0.158557, 0.019578), (0.090303004, 0.155295, 0.019578), (0.078951, 0.142081, 0.017557), (0.077822, 0.140888, 0.025105), (0.08162, 0.13936101, 0.021331001), (0.075154, 0.143608, 0.021331001), (0.08257, 0.141167, 0.024907), (0.086319, 0.147301, 0.01802), (0.080998, 0.150796, 0.01802), (0.076444, 0.145191, 0.024907), (0.086210005, 0.147294, 0.024333), (0.083330005, 0.141971, 0.017755), (0.077203006, 0.14599401, 0.017756), (0.080967, 0.150737, 0.024333), (0.077959, 0.14888, 0.021331001), (0.08163, 0.146134, 0.025844), (0.082021005, 0.146548, 0.016818), (0.085691005, 0.143802, 0.021331001), (-0.081437, 0.154447, -0.006747), (-0.081803, 0.1575, -0.013425), (-0.087519, 0.15859, -0.013131999), (-0.082903, 0.150937, -0.0125319995), (-0.08907, 0.15272701, -0.013425), (-0.090077, 0.156267, -0.0066959998), (-0.084888, 0.159675, -0.0066959998), (-0.086704, 0.150988, -0.006747), (-0.081581995, 0.15588601, -0.020903999), (-0.084773995, 0.156243, -0.019999), (-0.082929, 0.151601, -0.022956999), (-0.086549, 0.152624, -0.020903999), (-0.08688, 0.14939201, -0.018811), (-0.079611994, 0.154164, -0.018811), (-0.083335996, 0.158557, -0.017578), (-0.088303, 0.155295, -0.017578), (-0.076951, 0.142081, -0.015557), (-0.075821996, 0.140888, -0.023105), (-0.079619996, 0.13936101, -0.019331), (-0.073153995, 0.143608, -0.019331), (-0.08057, 0.141167, -0.022907), (-0.084318995, 0.147301, -0.01602), (-0.078998, 0.150796, -0.01602), (-0.074443996, 0.145191, -0.022907), (-0.08421, 0.147294, -0.022333), (-0.08133, 0.141971, -0.015755), (-0.075203, 0.14599401, -0.015756), (-0.078967, 0.150737, -0.022333), (-0.075959, 0.14888, -0.019331), (-0.079629995, 0.146134, -0.023844), (-0.080021, 0.146548, -0.014818), (-0.083691, 0.143802, -0.019331), (0.083437, 0.154447, -0.006747), (0.083803006, 0.1575, -0.013425), (0.089519, 0.15859, -0.013131999), (0.084903, 0.150937, -0.0125319995), (0.091070004, 0.15272701, -0.013425), (0.092077, 0.156267, -0.0066959998), (0.086888, 0.159675, -0.0066959998), (0.088704005, 0.150988, -0.006747), (0.083582, 0.15588601, -0.020903999), (0.086774, 0.156243, -0.019999), (0.084929004, 0.151601, -0.022956999), (0.088549, 0.152624, -0.020903999), (0.08888, 0.14939201, -0.018811), (0.081612, 0.154164, -0.018811), (0.085336, 0.158557, -0.017578), (0.090303004, 0.155295, -0.017578), (0.078951, 0.142081, -0.015557), (0.077822, 0.140888, -0.023105), (0.08162, 0.13936101, -0.019331), (0.075154, 0.143608, -0.019331), (0.08257, 0.141168, -0.022907), (0.086319, 0.147301, -0.01602), (0.080998, 0.150796, -0.01602), (0.076444, 0.14519, -0.022907), (0.086210005, 0.147294, -0.022333), (0.083330005, 0.141971, -0.015755), (0.077203006, 0.14599401, -0.015755), (0.080967, 0.150737, -0.022333), (0.077959, 0.14888, -0.019331), (0.08163, 0.146134, -0.023844), (0.082021005, 0.146548, -0.014818), (0.085691005, 0.143802, -0.019331), (-0.082218, 0.157722, 0.008728), (-0.082091995, 0.140769, 0.021331001), (-0.083435, 0.15174, 0.008643), (-0.088111, 0.158937, 0.008687), (-0.089373, 0.153024, 0.008728), (-0.08383, 0.154142, 0.023784), (-0.081175, 0.157018, 0.02016), (-0.088287994, 0.152347, 0.02016), (-0.086169, 0.15770501, 0.019417001), (-0.075865, 0.14075801, 0.021331001), (-0.078393996, 0.14411001, 0.016394), (-0.07733, 0.142984, 0.026269), (-0.073633, 0.14632401, 0.021331001), (-0.078019, 0.15142201, 0.02125), (-0.081571, 0.149002, 0.025472), (-0.081626, 0.148987, 0.016809), (-0.085249, 0.146675, 0.02125), (0.084218, 0.157722, 0.008728), (0.084092, 0.140769, 0.021331001), (0.085435, 0.15174, 0.008643), (0.090111, 0.158937, 0.008687), (0.091373004, 0.153024, 0.008728), (0.08583, 0.154142, 0.023784), (0.083175, 0.157018, 0.02016), (0.090288, 0.152347, 0.02016), (0.088169, 0.15770501, 0.019417001), (0.077865005, 0.14075801, 0.021331001), (0.080394, 0.14411001, 0.016394), (0.079330005, 0.142984, 0.026269), (0.075633004, 0.14632401, 0.021331001), (0.080019005, 0.15142201, 0.02125), (0.083571, 0.149002, 0.025472), (0.083626, 0.148987, 0.016809), (0.087249, 0.146675, 0.02125), (-0.082218, 0.157722, -0.006728), (-0.082091995, 0.140769, -0.019331), (-0.083435, 0.15174, -0.006643), (-0.088111, 0.158937, -0.006687), (-0.089373, 0.153024, -0.006728), (-0.08383, 0.154142, -0.021784), (-0.081175, 0.157018, -0.01816), (-0.088287994, 0.152347, -0.01816), (-0.086169, 0.15770501, -0.017417), (-0.075865, 0.14075801, -0.019331), (-0.078393996, 0.14411001, -0.014394), (-0.07733, 0.142984, -0.024269), (-0.073633, 0.14632401, -0.019331), (-0.078019, 0.15142201, -0.01925), (-0.081571, 0.149002, -0.023472), (-0.081626, 0.148987, -0.014808999), (-0.085249, 0.146675, -0.01925), (0.084218, 0.157722, -0.006728), (0.084092, 0.140769, -0.019331), (0.085435, 0.15174, -0.006643), (0.090111, 0.158937, -0.006687), (0.091373004, 0.153024, -0.006728), (0.08583, 0.154142, -0.021784), (0.083175, 0.157018, -0.01816), (0.090288, 0.152347, -0.01816), (0.088169, 0.15770501, -0.017417), (0.077865005, 0.14075801, -0.019331), (0.080394, 0.14411001, -0.014394), (0.079330005, 0.142984, -0.024269), (0.075633004, 0.14632401, -0.019331), (0.080019005, 0.15142201, -0.01925), (0.083571, 0.149002, -0.023472), (0.083626, 0.148987, -0.014808999), (0.087249, 0.146675, -0.01925)
-            ].into_iter().map(|v|{Vec3::new(v.0,v.1,v.2)}).collect();
-        let example_mesh_indices: Vec<usize> = vec!(
-            2, 54, 152, 53, 53, 152, 55, 3, 152, 56, 5, 55, 54, 4, 56, 152, 4, 57, 153, 56, 56, 153, 58, 5, 153, 59, 7, 58, 57, 6, 59, 153, 6, 60, 154, 59, 59, 154, 61, 7, 154, 62, 9, 61, 60, 8, 62, 154, 8, 63, 155, 62, 62, 155, 64, 9, 155, 65, 11, 64, 63, 10, 65, 155, 10, 66, 156, 65, 65, 156, 67, 11, 156, 52, 1, 67, 66, 0, 52, 156, 3, 69, 157, 53, 53, 157, 70, 2, 157, 68, 12, 70, 69, 13, 68, 157, 14, 71, 158, 72, 72, 158, 74, 16, 158, 73, 17, 74, 71, 15, 73, 158, 16, 74, 159, 75, 75, 159, 77, 18, 159, 76, 19, 77, 74, 17, 76, 159, 18, 77, 160, 78, 78, 160, 80, 20, 160, 79, 21, 80, 77, 19, 79, 160, 20, 80, 161, 81, 81, 161, 83, 22, 161, 82, 23, 83, 80, 21, 82, 161, 22, 83, 162, 84, 84, 162, 52, 0, 162, 85, 1, 52, 83, 23, 85, 162, 15, 71, 163, 87, 87, 163, 86, 25, 163, 88, 24, 86, 71, 14, 88, 163, 28, 90, 164, 91, 91, 164, 93, 30, 164, 92, 31, 93, 90, 29, 92, 164, 30, 93, 165, 94, 94, 165, 96, 32, 165, 95, 33, 96, 93, 31, 95, 165, 32, 96, 166, 97, 97, 166, 99, 34, 166, 98, 35, 99, 96, 33, 98, 166, 34, 99, 167, 100, 100, 167, 102, 36, 167, 101, 37, 102, 99, 35, 101, 167, 36, 102, 168, 103, 103, 168, 89, 26, 168, 104, 27, 89, 102, 37, 104, 168, 29, 90, 169, 106, 106, 169, 105, 39, 169, 107, 38, 105, 90, 28, 107, 169, 40, 109, 170, 108, 108, 170, 110, 41, 170, 111, 43, 110, 109, 42, 111, 170, 42, 112, 171, 111, 111, 171, 113, 43, 171, 114, 45, 113, 112, 44, 114, 171, 44, 115, 172, 114, 114, 172, 116, 45, 172, 117, 47, 116, 115, 46, 117, 172, 46, 118, 173, 117, 117, 173, 119, 47, 173, 120, 49, 119, 118, 48, 120, 173, 48, 121, 174, 120, 120, 174, 122, 49, 174, 89, 27, 122, 121, 26, 89, 174, 41, 124, 175, 108, 108, 175, 125, 40, 175, 123, 50, 125, 124, 51, 123, 175, 2, 128, 176, 54, 54, 176, 130, 4, 176, 91, 30, 130, 128, 28, 91, 176, 5, 131, 177, 55, 55, 177, 129, 3, 177, 92, 29, 129, 131, 31, 92, 177, 4, 130, 178, 57, 57, 178, 132, 6, 178, 94, 32, 132, 130, 30, 94, 178, 7, 133, 179, 58, 58, 179, 131, 5, 179, 95, 31, 131, 133, 33, 95, 179, 6, 132, 180, 60, 60, 180, 134, 8, 180, 97, 34, 134, 132, 32, 97, 180, 9, 135, 181, 61, 61, 181, 133, 7, 181, 98, 33, 133, 135, 35, 98, 181, 8, 134, 182, 63, 63, 182, 136, 10, 182, 100, 36, 136, 134, 34, 100, 182, 11, 137, 183, 64, 64, 183, 135, 9, 183, 101, 35, 135, 137, 37, 101, 183, 10, 136, 184, 66, 66, 184, 126, 0, 184, 103, 26, 126, 136, 36, 103, 184, 1, 127, 185, 67, 67, 185, 137, 11, 185, 104, 37, 137, 127, 27, 104, 185, 13, 139, 186, 68, 68, 186, 138, 12, 186, 105, 38, 138, 139, 39, 105, 186, 3, 129, 187, 69, 69, 187, 139, 13, 187, 106, 39, 139, 129, 29, 106, 187, 12, 138, 188, 70, 70, 188, 128, 2, 188, 107, 28, 128, 138, 38, 107, 188, 16, 142, 189, 72, 72, 189, 140, 14, 189, 109, 40, 140, 142, 42, 109, 189, 15, 141, 190, 73, 73, 190, 143, 17, 190, 110, 43, 143, 141, 41, 110, 190, 18, 144, 191, 75, 75, 191, 142, 16, 191, 112, 42, 142, 144, 44, 112, 191, 17, 143, 192, 76, 76, 192, 145, 19, 192, 113, 45, 145, 143, 43, 113, 192, 20, 146, 193, 78, 78, 193, 144, 18, 193, 115, 44, 144, 146, 46, 115, 193, 19, 145, 194, 79, 79, 194, 147, 21, 194, 116, 47, 147, 145, 45, 116, 194, 22, 148, 195, 81, 81, 195, 146, 20, 195, 118, 46, 146, 148, 48, 118, 195, 21, 147, 196, 82, 82, 196, 149, 23, 196, 119, 49, 149, 147, 47, 119, 196, 0, 126, 197, 84, 84, 197, 148, 22, 197, 121, 48, 148, 126, 26, 121, 197, 23, 149, 198, 85, 85, 198, 127, 1, 198, 122, 27, 127, 149, 49, 122, 198, 24, 150, 199, 86, 86, 199, 151, 25, 199, 123, 51, 151, 150, 50, 123, 199, 25, 151, 200, 87, 87, 200, 141, 15, 200, 124, 41, 141, 151, 51, 124, 200, 14, 140, 201, 88, 88, 201, 150, 24, 201, 125, 50, 150, 140, 40, 125, 201, 265, 489, 963, 800, 800, 963, 750, 392, 963, 797, 363, 750, 489, 250, 797, 963, 252, 463, 964, 801, 801, 964, 789, 366, 964, 803, 368, 789, 463, 253, 803, 964, 253, 465, 965, 803, 803, 965, 786, 368, 965, 805, 370, 786, 465, 254, 805, 965, 254, 467, 966, 805, 805, 966, 783, 370, 966, 807, 372, 783, 467, 255, 807, 966, 255, 469, 967, 807, 807, 967, 780, 372, 967, 809, 374, 780, 469, 256, 809, 967, 256, 471, 968, 809, 809, 968, 777, 374, 968, 811, 376, 777, 471, 257, 811, 968, 257, 473, 969, 811, 811, 969, 774, 376, 969, 813, 378, 774, 473, 258, 813, 969, 258, 475, 970, 813, 813, 970, 771, 378, 970, 815, 380, 771, 475, 259, 815, 970, 259, 477, 971, 815, 815, 971, 768, 380, 971, 817, 382, 768, 477, 260, 817, 971, 260, 479, 972, 817, 817, 972, 765, 382, 972, 819, 384, 765, 479, 261, 819, 972, 261, 481, 973, 819, 819, 973, 762, 384, 973, 821, 386, 762, 481, 262, 821, 973, 262, 483, 974, 821, 821, 974, 759, 386, 974, 823, 388, 759, 483, 263, 823, 974, 263, 485, 975, 823, 823, 975, 756, 388, 975, 825, 390, 756, 485, 264, 825, 975, 264, 487, 976, 825, 825, 976, 753, 390, 976, 800, 392, 753, 487, 265, 800, 976, 417, 866, 977, 890, 890, 977, 507, 410, 977, 496, 271, 507, 866, 272, 496, 977, 250, 459, 978, 797, 797, 978, 794, 363, 978, 798, 364, 794, 459, 251, 798, 978, 202, 538, 979, 523, 204, 523, 979, 524, 206, 524, 979, 525, 208, 525, 979, 526, 210, 526, 979, 527, 212, 527, 979, 528, 214, 528, 979, 529, 216, 529, 979, 530, 218, 530, 979, 531, 220, 531, 979, 532, 222, 532, 979, 533, 224, 533, 979, 534, 226, 534, 979, 535, 228, 535, 979, 536, 230, 536, 979, 537, 232, 537, 979, 538, 238, 636, 980, 558, 558, 980, 635, 237, 980, 622, 301, 635, 636, 302, 622, 980, 418, 867, 981, 889, 889, 981, 509, 411, 981, 501, 276, 509, 867, 277, 501, 981, 419, 868, 982, 888, 888, 982, 511, 412, 982, 506, 281, 511, 868, 267, 506, 982, 420, 869, 983, 887, 887, 983, 513, 413, 983, 494, 269, 513, 869, 270, 494, 983, 421, 870, 984, 886, 886, 984, 515, 414, 984, 499, 274, 515, 870, 275, 499, 984, 422, 871, 985, 885, 885, 985, 517, 415, 985, 504, 279, 517, 871, 280, 504, 985, 423, 872, 986, 884, 884, 986, 519, 416, 986, 492, 266, 519, 872, 268, 492, 986, 424, 873, 987, 883, 883, 987, 866, 417, 987, 497, 272, 866, 873, 273, 497, 987, 425, 874, 988, 882, 882, 988, 867, 418, 988, 502, 277, 867, 874, 278, 502, 988, 410, 507, 989, 881, 881, 989, 869, 420, 989, 495, 270, 869, 507, 271, 495, 989, 411, 509, 990, 880, 880, 990, 870, 421, 990, 500, 275, 870, 509, 276, 500, 990, 412, 511, 991, 879, 879, 991, 871, 422, 991, 505, 280, 871, 511, 281, 505, 991, 413, 513, 992, 878, 878, 992, 872, 423, 992, 493, 268, 872, 513, 269, 493, 992, 414, 515, 993, 877, 877, 993, 873, 424, 993, 498, 273, 873, 515, 274, 498, 993, 416, 519, 994, 876, 876, 994, 868, 419, 994, 491, 267, 868, 519, 266, 491, 994, 415, 517, 995, 875, 875, 995, 874, 425, 995, 503, 278, 874, 517, 279, 503, 995, 204, 539, 996, 523, 523, 996, 540, 202, 996, 459, 250, 540, 539, 251, 459, 996, 206, 541, 997, 524, 524, 997, 539, 204, 997, 461, 251, 539, 541, 252, 461, 997, 208, 542, 998, 525, 525, 998, 541, 206, 998, 463, 252, 541, 542, 253, 463, 998, 210, 543, 999, 526, 526, 999, 542, 208, 999, 465, 253, 542, 543, 254, 465, 999, 212, 544, 1000, 527, 527, 1000, 543, 210, 1000, 467, 254, 543, 544, 255, 467, 1000, 214, 545, 1001, 528, 528, 1001, 544, 212, 1001, 469, 255, 544, 545, 256, 469, 1001, 216, 546, 1002, 529, 529, 1002, 545, 214, 1002, 471, 256, 545, 546, 257, 471, 1002, 218, 547, 1003, 530, 530, 1003, 546, 216, 1003, 473, 257, 546, 547, 258, 473, 1003, 220, 548, 1004, 531, 531, 1004, 547, 218, 1004, 475, 258, 547, 548, 259, 475, 1004, 222, 549, 1005, 532, 532, 1005, 548, 220, 1005, 477, 259, 548, 549, 260, 477, 1005, 224, 550, 1006, 533, 533, 1006, 549, 222, 1006, 479, 260, 549, 550, 261, 479, 1006, 226, 551, 1007, 534, 534, 1007, 550, 224, 1007, 481, 261, 550, 551, 262, 481, 1007, 228, 552, 1008, 535, 535, 1008, 551, 226, 1008, 483, 262, 551, 552, 263, 483, 1008, 230, 553, 1009, 536, 536, 1009, 552, 228, 1009, 485, 263, 552, 553, 264, 485, 1009, 232, 554, 1010, 537, 537, 1010, 553, 230, 1010, 487, 264, 553, 554, 265, 487, 1010, 202, 540, 1011, 538, 538, 1011, 554, 232, 1011, 489, 265, 554, 540, 250, 489, 1011, 235, 571, 1012, 555, 555, 1012, 572, 234, 1012, 491, 266, 572, 571, 267, 491, 1012, 234, 572, 1013, 556, 556, 1013, 573, 236, 1013, 492, 268, 573, 572, 266, 492, 1013, 236, 573, 1014, 557, 557, 1014, 574, 237, 1014, 493, 269, 574, 573, 268, 493, 1014, 237, 574, 1015, 558, 558, 1015, 575, 238, 1015, 494, 270, 575, 574, 269, 494, 1015, 238, 575, 1016, 559, 559, 1016, 576, 239, 1016, 495, 271, 576, 575, 270, 495, 1016, 239, 576, 1017, 560, 560, 1017, 577, 240, 1017, 496, 272, 577, 576, 271, 496, 1017, 240, 577, 1018, 561, 561, 1018, 578, 241, 1018, 497, 273, 578, 577, 272, 497, 1018, 241, 578, 1019, 562, 562, 1019, 579, 242, 1019, 498, 274, 579, 578, 273, 498, 1019, 242, 579, 1020, 563, 563, 1020, 580, 243, 1020, 499, 275, 580, 579, 274, 499, 1020, 243, 580, 1021, 564, 564, 1021, 581, 244, 1021, 500, 276, 581, 580, 275, 500, 1021, 244, 581, 1022, 565, 565, 1022, 582, 245, 1022, 501, 277, 582, 581, 276, 501, 1022, 245, 582, 1023, 566, 566, 1023, 583, 246, 1023, 502, 278, 583, 582, 277, 502, 1023, 246, 583, 1024, 567, 567, 1024, 584, 247, 1024, 503, 279, 584, 583, 278, 503, 1024, 247, 584, 1025, 568, 568, 1025, 585, 248, 1025, 504, 280, 585, 584, 279, 504, 1025, 248, 585, 1026, 569, 569, 1026, 586, 249, 1026, 505, 281, 586, 585, 280, 505, 1026, 249, 586, 1027, 570, 570, 1027, 571, 235, 1027, 506, 267, 571, 586, 281, 506, 1027, 251, 461, 1028, 798, 798, 1028, 792, 364, 1028, 801, 366, 792, 461, 252, 801, 1028, 391, 752, 1029, 826, 826, 1029, 488, 231, 1029, 795, 233, 488, 752, 393, 795, 1029, 389, 755, 1030, 824, 824, 1030, 486, 229, 1030, 826, 231, 486, 755, 391, 826, 1030, 387, 758, 1031, 822, 822, 1031, 484, 227, 1031, 824, 229, 484, 758, 389, 824, 1031, 385, 761, 1032, 820, 820, 1032, 482, 225, 1032, 822, 227, 482, 761, 387, 822, 1032, 383, 764, 1033, 818, 818, 1033, 480, 223, 1033, 820, 225, 480, 764, 385, 820, 1033, 381, 767, 1034, 816, 816, 1034, 478, 221, 1034, 818, 223, 478, 767, 383, 818, 1034, 379, 770, 1035, 814, 814, 1035, 476, 219, 1035, 816, 221, 476, 770, 381, 816, 1035, 377, 773, 1036, 812, 812, 1036, 474, 217, 1036, 814, 219, 474, 773, 379, 814, 1036, 375, 776, 1037, 810, 810, 1037, 472, 215, 1037, 812, 217, 472, 776, 377, 812, 1037, 373, 779, 1038, 808, 808, 1038, 470, 213, 1038, 810, 215, 470, 779, 375, 810, 1038, 371, 782, 1039, 806, 806, 1039, 468, 211, 1039, 808, 213, 468, 782, 373, 808, 1039, 369, 785, 1040, 804, 804, 1040, 466, 209, 1040, 806, 211, 466, 785, 371, 806, 1040, 367, 788, 1041, 802, 802, 1041, 464, 207, 1041, 804, 209, 464, 788, 369, 804, 1041, 365, 791, 1042, 799, 799, 1042, 462, 205, 1042, 802, 207, 462, 791, 367, 802, 1042, 362, 793, 1043, 796, 796, 1043, 460, 203, 1043, 799, 205, 460, 793, 365, 799, 1043, 229, 597, 1044, 484, 484, 1044, 522, 227, 1044, 603, 297, 522, 597, 292, 603, 1044, 205, 599, 1045, 460, 460, 1045, 512, 203, 1045, 604, 287, 512, 599, 294, 604, 1045, 219, 595, 1046, 474, 474, 1046, 521, 217, 1046, 605, 296, 521, 595, 290, 605, 1046, 209, 593, 1047, 464, 464, 1047, 520, 207, 1047, 606, 295, 520, 593, 288, 606, 1047, 233, 591, 1048, 488, 488, 1048, 518, 231, 1048, 607, 293, 518, 591, 286, 607, 1048, 223, 589, 1049, 478, 478, 1049, 516, 221, 1049, 608, 291, 516, 589, 284, 608, 1049, 213, 587, 1050, 468, 468, 1050, 514, 211, 1050, 609, 289, 514, 587, 282, 609, 1050, 227, 522, 1051, 482, 482, 1051, 510, 225, 1051, 610, 285, 510, 522, 297, 610, 1051, 217, 521, 1052, 472, 472, 1052, 508, 215, 1052, 611, 283, 508, 521, 296, 611, 1052, 207, 520, 1053, 462, 462, 1053, 599, 205, 1053, 612, 294, 599, 520, 295, 612, 1053, 231, 518, 1054, 486, 486, 1054, 597, 229, 1054, 613, 292, 597, 518, 293, 613, 1054, 221, 516, 1055, 476, 476, 1055, 595, 219, 1055, 614, 290, 595, 516, 291, 614, 1055, 211, 514, 1056, 466, 466, 1056, 593, 209, 1056, 615, 288, 593, 514, 289, 615, 1056, 203, 512, 1057, 490, 490, 1057, 591, 233, 1057, 616, 286, 591, 512, 287, 616, 1057, 225, 510, 1058, 480, 480, 1058, 589, 223, 1058, 617, 284, 589, 510, 285, 617, 1058, 215, 508, 1059, 470, 470, 1059, 587, 213, 1059, 618, 282, 587, 508, 283, 618, 1059, 310, 668, 1060, 630, 630, 1060, 667, 309, 1060, 662, 325, 667, 668, 326, 662, 1060, 246, 638, 1061, 566, 566, 1061, 637, 245, 1061, 630, 309, 637, 638, 310, 630, 1061, 239, 639, 1062, 559, 559, 1062, 636, 238, 1062, 623, 302, 636, 639, 303, 623, 1062, 247, 640, 1063, 567, 567, 1063, 638, 246, 1063, 631, 310, 638, 640, 311, 631, 1063, 240, 641, 1064, 560, 560, 1064, 639, 239, 1064, 624, 303, 639, 641, 304, 624, 1064, 248, 642, 1065, 568, 568, 1065, 640, 247, 1065, 632, 311, 640, 642, 312, 632, 1065, 241, 643, 1066, 561, 561, 1066, 641, 240, 1066, 625, 304, 641, 643, 305, 625, 1066, 249, 644, 1067, 569, 569, 1067, 642, 248, 1067, 633, 312, 642, 644, 313, 633, 1067, 242, 645, 1068, 562, 562, 1068, 643, 241, 1068, 626, 305, 643, 645, 306, 626, 1068, 234, 647, 1069, 555, 555, 1069, 646, 235, 1069, 619, 299, 646, 647, 298, 619, 1069, 235, 646, 1070, 570, 570, 1070, 644, 249, 1070, 634, 313, 644, 646, 299, 634, 1070, 243, 648, 1071, 563, 563, 1071, 645, 242, 1071, 627, 306, 645, 648, 307, 627, 1071, 236, 649, 1072, 556, 556, 1072, 647, 234, 1072, 620, 298, 647, 649, 300, 620, 1072, 244, 650, 1073, 564, 564, 1073, 648, 243, 1073, 628, 307, 648, 650, 308, 628, 1073, 237, 635, 1074, 557, 557, 1074, 649, 236, 1074, 621, 300, 649, 635, 301, 621, 1074, 245, 637, 1075, 565, 565, 1075, 650, 244, 1075, 629, 308, 650, 637, 309, 629, 1075, 446, 934, 1076, 962, 962, 1076, 699, 443, 1076, 684, 426, 699, 934, 428, 684, 1076, 303, 670, 1077, 623, 623, 1077, 669, 302, 1077, 655, 318, 669, 670, 319, 655, 1077, 311, 671, 1078, 631, 631, 1078, 668, 310, 1078, 663, 326, 668, 671, 327, 663, 1078, 304, 672, 1079, 624, 624, 1079, 670, 303, 1079, 656, 319, 670, 672, 320, 656, 1079, 312, 673, 1080, 632, 632, 1080, 671, 311, 1080, 664, 327, 671, 673, 328, 664, 1080, 305, 674, 1081, 625, 625, 1081, 672, 304, 1081, 657, 320, 672, 674, 321, 657, 1081, 313, 675, 1082, 633, 633, 1082, 673, 312, 1082, 665, 328, 673, 675, 329, 665, 1082, 306, 676, 1083, 626, 626, 1083, 674, 305, 1083, 658, 321, 674, 676, 322, 658, 1083, 298, 678, 1084, 619, 619, 1084, 677, 299, 1084, 651, 315, 677, 678, 314, 651, 1084, 299, 677, 1085, 634, 634, 1085, 675, 313, 1085, 666, 329, 675, 677, 315, 666, 1085, 307, 679, 1086, 627, 627, 1086, 676, 306, 1086, 659, 322, 676, 679, 323, 659, 1086, 300, 680, 1087, 620, 620, 1087, 678, 298, 1087, 652, 314, 678, 680, 316, 652, 1087, 308, 681, 1088, 628, 628, 1088, 679, 307, 1088, 660, 323, 679, 681, 324, 660, 1088, 301, 682, 1089, 621, 621, 1089, 680, 300, 1089, 653, 316, 680, 682, 317, 653, 1089, 309, 667, 1090, 629, 629, 1090, 681, 308, 1090, 661, 324, 681, 667, 325, 661, 1090, 302, 669, 1091, 622, 622, 1091, 682, 301, 1091, 654, 317, 682, 669, 318, 654, 1091, 348, 932, 1092, 731, 731, 1092, 729, 360, 1092, 947, 458, 729, 932, 444, 947, 1092, 447, 935, 1093, 961, 961, 1093, 701, 444, 1093, 692, 435, 701, 935, 436, 692, 1093, 448, 936, 1094, 960, 960, 1094, 934, 446, 1094, 685, 428, 934, 936, 429, 685, 1094, 449, 937, 1095, 959, 959, 1095, 935, 447, 1095, 693, 436, 935, 937, 437, 693, 1095, 450, 938, 1096, 958, 958, 1096, 936, 448, 1096, 686, 429, 936, 938, 430, 686, 1096, 451, 939, 1097, 957, 957, 1097, 937, 449, 1097, 694, 437, 937, 939, 438, 694, 1097, 452, 940, 1098, 956, 956, 1098, 938, 450, 1098, 687, 430, 938, 940, 431, 687, 1098, 453, 941, 1099, 955, 955, 1099, 939, 451, 1099, 695, 438, 939, 941, 439, 695, 1099, 454, 942, 1100, 954, 954, 1100, 940, 452, 1100, 688, 431, 940, 942, 432, 688, 1100, 455, 943, 1101, 953, 953, 1101, 941, 453, 1101, 696, 439, 941, 943, 440, 696, 1101, 456, 944, 1102, 952, 952, 1102, 942, 454, 1102, 689, 432, 942, 944, 433, 689, 1102, 457, 945, 1103, 951, 951, 1103, 943, 455, 1103, 697, 440, 943, 945, 441, 697, 1103, 458, 946, 1104, 950, 950, 1104, 944, 456, 1104, 690, 433, 944, 946, 434, 690, 1104, 443, 699, 1105, 949, 949, 1105, 714, 445, 1105, 683, 427, 714, 699, 426, 683, 1105, 445, 714, 1106, 948, 948, 1106, 945, 457, 1106, 698, 441, 945, 714, 427, 698, 1106, 444, 701, 1107, 947, 947, 1107, 946, 458, 1107, 691, 434, 946, 701, 435, 691, 1107, 323, 717, 1108, 659, 659, 1108, 713, 322, 1108, 731, 360, 713, 717, 348, 731, 1108, 315, 730, 1109, 666, 666, 1109, 712, 329, 1109, 732, 359, 712, 730, 361, 732, 1109, 314, 715, 1110, 651, 651, 1110, 730, 315, 1110, 733, 361, 730, 715, 346, 733, 1110, 322, 713, 1111, 658, 658, 1111, 711, 321, 1111, 734, 358, 711, 713, 360, 734, 1111, 329, 712, 1112, 665, 665, 1112, 710, 328, 1112, 735, 357, 710, 712, 359, 735, 1112, 321, 711, 1113, 657, 657, 1113, 709, 320, 1113, 736, 356, 709, 711, 358, 736, 1113, 328, 710, 1114, 664, 664, 1114, 708, 327, 1114, 737, 355, 708, 710, 357, 737, 1114, 320, 709, 1115, 656, 656, 1115, 707, 319, 1115, 738, 354, 707, 709, 356, 738, 1115, 327, 708, 1116, 663, 663, 1116, 706, 326, 1116, 739, 353, 706, 708, 355, 739, 1116, 319, 707, 1117, 655, 655, 1117, 705, 318, 1117, 740, 352, 705, 707, 354, 740, 1117, 326, 706, 1118, 662, 662, 1118, 704, 325, 1118, 741, 351, 704, 706, 353, 741, 1118, 318, 705, 1119, 654, 654, 1119, 703, 317, 1119, 742, 350, 703, 705, 352, 742, 1119, 325, 704, 1120, 661, 661, 1120, 702, 324, 1120, 743, 349, 702, 704, 351, 743, 1120, 317, 703, 1121, 653, 653, 1121, 700, 316, 1121, 744, 347, 700, 703, 350, 744, 1121, 324, 702, 1122, 660, 660, 1122, 717, 323, 1122, 745, 348, 717, 702, 349, 745, 1122, 316, 700, 1123, 652, 652, 1123, 715, 314, 1123, 746, 346, 715, 700, 347, 746, 1123, 393, 747, 1124, 748, 748, 1124, 749, 362, 1124, 750, 363, 749, 747, 392, 750, 1124, 391, 751, 1125, 752, 752, 1125, 747, 393, 1125, 753, 392, 747, 751, 390, 753, 1125, 389, 754, 1126, 755, 755, 1126, 751, 391, 1126, 756, 390, 751, 754, 388, 756, 1126, 387, 757, 1127, 758, 758, 1127, 754, 389, 1127, 759, 388, 754, 757, 386, 759, 1127, 385, 760, 1128, 761, 761, 1128, 757, 387, 1128, 762, 386, 757, 760, 384, 762, 1128, 383, 763, 1129, 764, 764, 1129, 760, 385, 1129, 765, 384, 760, 763, 382, 765, 1129, 381, 766, 1130, 767, 767, 1130, 763, 383, 1130, 768, 382, 763, 766, 380, 768, 1130, 379, 769, 1131, 770, 770, 1131, 766, 381, 1131, 771, 380, 766, 769, 378, 771, 1131, 377, 772, 1132, 773, 773, 1132, 769, 379, 1132, 774, 378, 769, 772, 376, 774, 1132, 375, 775, 1133, 776, 776, 1133, 772, 377, 1133, 777, 376, 772, 775, 374, 777, 1133, 373, 778, 1134, 779, 779, 1134, 775, 375, 1134, 780, 374, 775, 778, 372, 780, 1134, 371, 781, 1135, 782, 782, 1135, 778, 373, 1135, 783, 372, 778, 781, 370, 783, 1135, 369, 784, 1136, 785, 785, 1136, 781, 371, 1136, 786, 370, 781, 784, 368, 786, 1136, 367, 787, 1137, 788, 788, 1137, 784, 369, 1137, 789, 368, 784, 787, 366, 789, 1137, 365, 790, 1138, 791, 791, 1138, 787, 367, 1138, 792, 366, 787, 790, 364, 792, 1138, 362, 749, 1139, 793, 793, 1139, 790, 365, 1139, 794, 364, 790, 749, 363, 794, 1139, 393, 748, 1140, 795, 795, 1140, 490, 233, 1140, 796, 203, 490, 748, 362, 796, 1140, 292, 832, 1141, 603, 603, 1141, 602, 297, 1141, 843, 409, 602, 832, 399, 843, 1141, 294, 833, 1142, 604, 604, 1142, 592, 287, 1142, 844, 403, 592, 833, 400, 844, 1142, 290, 831, 1143, 605, 605, 1143, 601, 296, 1143, 845, 408, 601, 831, 398, 845, 1143, 288, 830, 1144, 606, 606, 1144, 600, 295, 1144, 846, 407, 600, 830, 397, 846, 1144, 286, 829, 1145, 607, 607, 1145, 598, 293, 1145, 847, 406, 598, 829, 396, 847, 1145, 284, 828, 1146, 608, 608, 1146, 596, 291, 1146, 848, 405, 596, 828, 395, 848, 1146, 282, 827, 1147, 609, 609, 1147, 594, 289, 1147, 849, 404, 594, 827, 394, 849, 1147, 297, 602, 1148, 610, 610, 1148, 590, 285, 1148, 850, 402, 590, 602, 409, 850, 1148, 296, 601, 1149, 611, 611, 1149, 588, 283, 1149, 851, 401, 588, 601, 408, 851, 1149, 295, 600, 1150, 612, 612, 1150, 833, 294, 1150, 852, 400, 833, 600, 407, 852, 1150, 293, 598, 1151, 613, 613, 1151, 832, 292, 1151, 853, 399, 832, 598, 406, 853, 1151, 291, 596, 1152, 614, 614, 1152, 831, 290, 1152, 854, 398, 831, 596, 405, 854, 1152, 289, 594, 1153, 615, 615, 1153, 830, 288, 1153, 855, 397, 830, 594, 404, 855, 1153, 287, 592, 1154, 616, 616, 1154, 829, 286, 1154, 856, 396, 829, 592, 403, 856, 1154, 285, 590, 1155, 617, 617, 1155, 828, 284, 1155, 857, 395, 828, 590, 402, 857, 1155, 283, 588, 1156, 618, 618, 1156, 827, 282, 1156, 858, 394, 827, 588, 401, 858, 1156, 399, 864, 1157, 843, 843, 1157, 842, 409, 1157, 875, 425, 842, 864, 415, 875, 1157, 400, 865, 1158, 844, 844, 1158, 836, 403, 1158, 876, 419, 836, 865, 416, 876, 1158, 398, 863, 1159, 845, 845, 1159, 841, 408, 1159, 877, 424, 841, 863, 414, 877, 1159, 397, 862, 1160, 846, 846, 1160, 840, 407, 1160, 878, 423, 840, 862, 413, 878, 1160, 396, 861, 1161, 847, 847, 1161, 839, 406, 1161, 879, 422, 839, 861, 412, 879, 1161, 395, 860, 1162, 848, 848, 1162, 838, 405, 1162, 880, 421, 838, 860, 411, 880, 1162, 394, 859, 1163, 849, 849, 1163, 837, 404, 1163, 881, 420, 837, 859, 410, 881, 1163, 409, 842, 1164, 850, 850, 1164, 835, 402, 1164, 882, 418, 835, 842, 425, 882, 1164, 408, 841, 1165, 851, 851, 1165, 834, 401, 1165, 883, 417, 834, 841, 424, 883, 1165, 407, 840, 1166, 852, 852, 1166, 865, 400, 1166, 884, 416, 865, 840, 423, 884, 1166, 406, 839, 1167, 853, 853, 1167, 864, 399, 1167, 885, 415, 864, 839, 422, 885, 1167, 405, 838, 1168, 854, 854, 1168, 863, 398, 1168, 886, 414, 863, 838, 421, 886, 1168, 404, 837, 1169, 855, 855, 1169, 862, 397, 1169, 887, 413, 862, 837, 420, 887, 1169, 403, 836, 1170, 856, 856, 1170, 861, 396, 1170, 888, 412, 861, 836, 419, 888, 1170, 402, 835, 1171, 857, 857, 1171, 860, 395, 1171, 889, 411, 860, 835, 418, 889, 1171, 401, 834, 1172, 858, 858, 1172, 859, 394, 1172, 890, 410, 859, 834, 417, 890, 1172, 331, 907, 1173, 891, 891, 1173, 908, 330, 1173, 683, 426, 908, 907, 427, 683, 1173, 330, 908, 1174, 892, 892, 1174, 909, 332, 1174, 684, 428, 909, 908, 426, 684, 1174, 332, 909, 1175, 893, 893, 1175, 910, 333, 1175, 685, 429, 910, 909, 428, 685, 1175, 333, 910, 1176, 894, 894, 1176, 911, 334, 1176, 686, 430, 911, 910, 429, 686, 1176, 334, 911, 1177, 895, 895, 1177, 912, 335, 1177, 687, 431, 912, 911, 430, 687, 1177, 335, 912, 1178, 896, 896, 1178, 913, 336, 1178, 688, 432, 913, 912, 431, 688, 1178, 336, 913, 1179, 897, 897, 1179, 914, 337, 1179, 689, 433, 914, 913, 432, 689, 1179, 337, 914, 1180, 898, 898, 1180, 915, 338, 1180, 690, 434, 915, 914, 433, 690, 1180, 338, 915, 1181, 899, 899, 1181, 916, 339, 1181, 691, 435, 916, 915, 434, 691, 1181, 339, 916, 1182, 900, 900, 1182, 917, 340, 1182, 692, 436, 917, 916, 435, 692, 1182, 340, 917, 1183, 901, 901, 1183, 918, 341, 1183, 693, 437, 918, 917, 436, 693, 1183, 341, 918, 1184, 902, 902, 1184, 919, 342, 1184, 694, 438, 919, 918, 437, 694, 1184, 342, 919, 1185, 903, 903, 1185, 920, 343, 1185, 695, 439, 920, 919, 438, 695, 1185, 343, 920, 1186, 904, 904, 1186, 921, 344, 1186, 696, 440, 921, 920, 439, 696, 1186, 344, 921, 1187, 905, 905, 1187, 922, 345, 1187, 697, 441, 922, 921, 440, 697, 1187, 345, 922, 1188, 906, 906, 1188, 907, 331, 1188, 698, 427, 907, 922, 441, 698, 1188, 345, 906, 1189, 905, 905, 1189, 924, 344, 1189, 923, 442, 924, 906, 331, 923, 1189, 343, 904, 1190, 903, 903, 1190, 925, 342, 1190, 924, 442, 925, 904, 344, 924, 1190, 341, 902, 1191, 901, 901, 1191, 926, 340, 1191, 925, 442, 926, 902, 342, 925, 1191, 339, 900, 1192, 899, 899, 1192, 927, 338, 1192, 926, 442, 927, 900, 340, 926, 1192, 337, 898, 1193, 897, 897, 1193, 928, 336, 1193, 927, 442, 928, 898, 338, 927, 1193, 335, 896, 1194, 895, 895, 1194, 929, 334, 1194, 928, 442, 929, 896, 336, 928, 1194, 333, 894, 1195, 893, 893, 1195, 930, 332, 1195, 929, 442, 930, 894, 334, 929, 1195, 361, 933, 1196, 732, 732, 1196, 728, 359, 1196, 948, 457, 728, 933, 445, 948, 1196, 346, 931, 1197, 733, 733, 1197, 933, 361, 1197, 949, 445, 933, 931, 443, 949, 1197, 360, 729, 1198, 734, 734, 1198, 727, 358, 1198, 950, 456, 727, 729, 458, 950, 1198, 359, 728, 1199, 735, 735, 1199, 726, 357, 1199, 951, 455, 726, 728, 457, 951, 1199, 358, 727, 1200, 736, 736, 1200, 725, 356, 1200, 952, 454, 725, 727, 456, 952, 1200, 357, 726, 1201, 737, 737, 1201, 724, 355, 1201, 953, 453, 724, 726, 455, 953, 1201, 356, 725, 1202, 738, 738, 1202, 723, 354, 1202, 954, 452, 723, 725, 454, 954, 1202, 355, 724, 1203, 739, 739, 1203, 722, 353, 1203, 955, 451, 722, 724, 453, 955, 1203, 354, 723, 1204, 740, 740, 1204, 721, 352, 1204, 956, 450, 721, 723, 452, 956, 1204, 353, 722, 1205, 741, 741, 1205, 720, 351, 1205, 957, 449, 720, 722, 451, 957, 1205, 352, 721, 1206, 742, 742, 1206, 719, 350, 1206, 958, 448, 719, 721, 450, 958, 1206, 351, 720, 1207, 743, 743, 1207, 718, 349, 1207, 959, 447, 718, 720, 449, 959, 1207, 350, 719, 1208, 744, 744, 1208, 716, 347, 1208, 960, 446, 716, 719, 448, 960, 1208, 349, 718, 1209, 745, 745, 1209, 932, 348, 1209, 961, 444, 932, 718, 447, 961, 1209, 347, 716, 1210, 746, 746, 1210, 931, 346, 1210, 962, 443, 931, 716, 446, 962, 1210, 332, 930, 1211, 892, 892, 1211, 891, 330, 1211, 923, 331, 891, 930, 442, 923, 1211, 1218, 1276, 1324, 1264, 1264, 1324, 1275, 1219, 1324, 1273, 1227, 1275, 1276, 1226, 1273, 1324, 1213, 1278, 1325, 1254, 1254, 1325, 1277, 1214, 1325, 1268, 1222, 1277, 1278, 1221, 1268, 1325, 1216, 1280, 1326, 1260, 1260, 1326, 1279, 1217, 1326, 1271, 1225, 1279, 1280, 1224, 1271, 1326, 1219, 1275, 1327, 1266, 1266, 1327, 1281, 1212, 1327, 1274, 1220, 1281, 1275, 1227, 1274, 1327, 1212, 1281, 1328, 1252, 1252, 1328, 1278, 1213, 1328, 1267, 1221, 1278, 1281, 1220, 1267, 1328, 1214, 1277, 1329, 1256, 1256, 1329, 1282, 1215, 1329, 1269, 1223, 1282, 1277, 1222, 1269, 1329, 1217, 1279, 1330, 1262, 1262, 1330, 1276, 1218, 1330, 1272, 1226, 1276, 1279, 1225, 1272, 1330, 1215, 1282, 1331, 1258, 1258, 1331, 1280, 1216, 1331, 1270, 1224, 1280, 1282, 1223, 1270, 1331, 1223, 1291, 1332, 1270, 1270, 1332, 1292, 1224, 1332, 1286, 1232, 1292, 1291, 1231, 1286, 1332, 1221, 1293, 1333, 1268, 1268, 1333, 1294, 1222, 1333, 1284, 1230, 1294, 1293, 1229, 1284, 1333, 1226, 1295, 1334, 1273, 1273, 1334, 1296, 1227, 1334, 1289, 1235, 1296, 1295, 1234, 1289, 1334, 1224, 1292, 1335, 1271, 1271, 1335, 1297, 1225, 1335, 1287, 1233, 1297, 1292, 1232, 1287, 1335, 1222, 1294, 1336, 1269, 1269, 1336, 1291, 1223, 1336, 1285, 1231, 1291, 1294, 1230, 1285, 1336, 1220, 1298, 1337, 1267, 1267, 1337, 1293, 1221, 1337, 1283, 1229, 1293, 1298, 1228, 1283, 1337, 1227, 1296, 1338, 1274, 1274, 1338, 1298, 1220, 1338, 1290, 1228, 1298, 1296, 1235, 1290, 1338, 1225, 1297, 1339, 1272, 1272, 1339, 1295, 1226, 1339, 1288, 1234, 1295, 1297, 1233, 1288, 1339, 1234, 1307, 1340, 1289, 1289, 1340, 1308, 1235, 1340, 1305, 1243, 1308, 1307, 1242, 1305, 1340, 1232, 1309, 1341, 1287, 1287, 1341, 1310, 1233, 1341, 1303, 1241, 1310, 1309, 1240, 1303, 1341, 1230, 1311, 1342, 1285, 1285, 1342, 1312, 1231, 1342, 1301, 1239, 1312, 1311, 1238, 1301, 1342, 1228, 1313, 1343, 1283, 1283, 1343, 1314, 1229, 1343, 1299, 1237, 1314, 1313, 1236, 1299, 1343, 1235, 1308, 1344, 1290, 1290, 1344, 1313, 1228, 1344, 1306, 1236, 1313, 1308, 1243, 1306, 1344, 1233, 1310, 1345, 1288, 1288, 1345, 1307, 1234, 1345, 1304, 1242, 1307, 1310, 1241, 1304, 1345, 1231, 1312, 1346, 1286, 1286, 1346, 1309, 1232, 1346, 1302, 1240, 1309, 1312, 1239, 1302, 1346, 1229, 1314, 1347, 1284, 1284, 1347, 1311, 1230, 1347, 1300, 1238, 1311, 1314, 1237, 1300, 1347, 1218, 1264, 1348, 1263, 1263, 1348, 1316, 1250, 1348, 1265, 1251, 1316, 1264, 1219, 1265, 1348, 1217, 1262, 1349, 1261, 1261, 1349, 1317, 1249, 1349, 1263, 1250, 1317, 1262, 1218, 1263, 1349, 1216, 1260, 1350, 1259, 1259, 1350, 1318, 1248, 1350, 1261, 1249, 1318, 1260, 1217, 1261, 1350, 1215, 1258, 1351, 1257, 1257, 1351, 1319, 1247, 1351, 1259, 1248, 1319, 1258, 1216, 1259, 1351, 1214, 1256, 1352, 1255, 1255, 1352, 1320, 1246, 1352, 1257, 1247, 1320, 1256, 1215, 1257, 1352, 1213, 1254, 1353, 1253, 1253, 1353, 1321, 1245, 1353, 1255, 1246, 1321, 1254, 1214, 1255, 1353, 1212, 1252, 1354, 1315, 1315, 1354, 1322, 1244, 1354, 1253, 1245, 1322, 1252, 1213, 1253, 1354, 1219, 1266, 1355, 1265, 1265, 1355, 1323, 1251, 1355, 1315, 1244, 1323, 1266, 1212, 1315, 1355, 1362, 1408, 1468, 1420, 1408, 1363, 1419, 1468, 1468, 1419, 1371, 1417, 1420, 1468, 1417, 1370, 1357, 1398, 1469, 1422, 1398, 1358, 1421, 1469, 1469, 1421, 1366, 1412, 1422, 1469, 1412, 1365, 1360, 1404, 1470, 1424, 1404, 1361, 1423, 1470, 1470, 1423, 1369, 1415, 1424, 1470, 1415, 1368, 1363, 1410, 1471, 1419, 1410, 1356, 1425, 1471, 1471, 1425, 1364, 1418, 1419, 1471, 1418, 1371, 1356, 1396, 1472, 1425, 1396, 1357, 1422, 1472, 1472, 1422, 1365, 1411, 1425, 1472, 1411, 1364, 1358, 1400, 1473, 1421, 1400, 1359, 1426, 1473, 1473, 1426, 1367, 1413, 1421, 1473, 1413, 1366, 1361, 1406, 1474, 1423, 1406, 1362, 1420, 1474, 1474, 1420, 1370, 1416, 1423, 1474, 1416, 1369, 1359, 1402, 1475, 1426, 1402, 1360, 1424, 1475, 1475, 1424, 1368, 1414, 1426, 1475, 1414, 1367, 1367, 1414, 1476, 1435, 1414, 1368, 1436, 1476, 1476, 1436, 1376, 1430, 1435, 1476, 1430, 1375, 1365, 1412, 1477, 1437, 1412, 1366, 1438, 1477, 1477, 1438, 1374, 1428, 1437, 1477, 1428, 1373, 1370, 1417, 1478, 1439, 1417, 1371, 1440, 1478, 1478, 1440, 1379, 1433, 1439, 1478, 1433, 1378, 1368, 1415, 1479, 1436, 1415, 1369, 1441, 1479, 1479, 1441, 1377, 1431, 1436, 1479, 1431, 1376, 1366, 1413, 1480, 1438, 1413, 1367, 1435, 1480, 1480, 1435, 1375, 1429, 1438, 1480, 1429, 1374, 1364, 1411, 1481, 1442, 1411, 1365, 1437, 1481, 1481, 1437, 1373, 1427, 1442, 1481, 1427, 1372, 1371, 1418, 1482, 1440, 1418, 1364, 1442, 1482, 1482, 1442, 1372, 1434, 1440, 1482, 1434, 1379, 1369, 1416, 1483, 1441, 1416, 1370, 1439, 1483, 1483, 1439, 1378, 1432, 1441, 1483, 1432, 1377, 1378, 1433, 1484, 1451, 1433, 1379, 1452, 1484, 1484, 1452, 1387, 1449, 1451, 1484, 1449, 1386, 1376, 1431, 1485, 1453, 1431, 1377, 1454, 1485, 1485, 1454, 1385, 1447, 1453, 1485, 1447, 1384, 1374, 1429, 1486, 1455, 1429, 1375, 1456, 1486, 1486, 1456, 1383, 1445, 1455, 1486, 1445, 1382, 1372, 1427, 1487, 1457, 1427, 1373, 1458, 1487, 1487, 1458, 1381, 1443, 1457, 1487, 1443, 1380, 1379, 1434, 1488, 1452, 1434, 1372, 1457, 1488, 1488, 1457, 1380, 1450, 1452, 1488, 1450, 1387, 1377, 1432, 1489, 1454, 1432, 1378, 1451, 1489, 1489, 1451, 1386, 1448, 1454, 1489, 1448, 1385, 1375, 1430, 1490, 1456, 1430, 1376, 1453, 1490, 1490, 1453, 1384, 1446, 1456, 1490, 1446, 1383, 1373, 1428, 1491, 1458, 1428, 1374, 1455, 1491, 1491, 1455, 1382, 1444, 1458, 1491, 1444, 1381, 1362, 1407, 1492, 1408, 1407, 1394, 1460, 1492, 1492, 1460, 1395, 1409, 1408, 1492, 1409, 1363, 1361, 1405, 1493, 1406, 1405, 1393, 1461, 1493, 1493, 1461, 1394, 1407, 1406, 1493, 1407, 1362, 1360, 1403, 1494, 1404, 1403, 1392, 1462, 1494, 1494, 1462, 1393, 1405, 1404, 1494, 1405, 1361, 1359, 1401, 1495, 1402, 1401, 1391, 1463, 1495, 1495, 1463, 1392, 1403, 1402, 1495, 1403, 1360, 1358, 1399, 1496, 1400, 1399, 1390, 1464, 1496, 1496, 1464, 1391, 1401, 1400, 1496, 1401, 1359, 1357, 1397, 1497, 1398, 1397, 1389, 1465, 1497, 1497, 1465, 1390, 1399, 1398, 1497, 1399, 1358, 1356, 1459, 1498, 1396, 1459, 1388, 1466, 1498, 1498, 1466, 1389, 1397, 1396, 1498, 1397, 1357, 1363, 1409, 1499, 1410, 1409, 1395, 1467, 1499, 1499, 1467, 1388, 1459, 1410, 1499, 1459, 1356, 1236, 1380, 1443, 1299, 1299, 1443, 1381, 1237, 1237, 1381, 1444, 1300, 1300, 1444, 1382, 1238, 1238, 1382, 1445, 1301, 1301, 1445, 1383, 1239, 1239, 1383, 1446, 1302, 1302, 1446, 1384, 1240, 1240, 1384, 1447, 1303, 1303, 1447, 1385, 1241, 1241, 1385, 1448, 1304, 1304, 1448, 1386, 1242, 1242, 1386, 1449, 1305, 1305, 1449, 1387, 1243, 1243, 1387, 1450, 1306, 1306, 1450, 1380, 1236, 1316, 1460, 1394, 1250, 1251, 1395, 1460, 1316, 1317, 1461, 1393, 1249, 1250, 1394, 1461, 1317, 1318, 1462, 1392, 1248, 1249, 1393, 1462, 1318, 1319, 1463, 1391, 1247, 1248, 1392, 1463, 1319, 1320, 1464, 1390, 1246, 1247, 1391, 1464, 1320, 1321, 1465, 1389, 1245, 1246, 1390, 1465, 1321, 1322, 1466, 1388, 1244, 1245, 1389, 1466, 1322, 1323, 1467, 1395, 1251, 1244, 1388, 1467, 1323, 1504, 1583, 1708, 1578, 1578, 1708, 1573, 1500, 1708, 1572, 1501, 1573, 1583, 1505, 1572, 1708, 1517, 1607, 1709, 1601, 1601, 1709, 1594, 1513, 1709, 1596, 1515, 1594, 1607, 1516, 1596, 1709, 1503, 1575, 1710, 1579, 1579, 1710, 1580, 1507, 1710, 1572, 1505, 1580, 1575, 1501, 1572, 1710, 1500, 1574, 1711, 1578, 1578, 1711, 1581, 1504, 1711, 1577, 1506, 1581, 1574, 1502, 1577, 1711, 1502, 1576, 1712, 1577, 1577, 1712, 1582, 1506, 1712, 1579, 1507, 1582, 1576, 1503, 1579, 1712, 1509, 1584, 1713, 1585, 1585, 1713, 1587, 1510, 1713, 1586, 1511, 1587, 1584, 1508, 1586, 1713, 1500, 1573, 1714, 1590, 1590, 1714, 1584, 1509, 1714, 1589, 1508, 1584, 1573, 1501, 1589, 1714, 1503, 1576, 1715, 1588, 1588, 1715, 1587, 1511, 1715, 1591, 1510, 1587, 1576, 1502, 1591, 1715, 1502, 1574, 1716, 1591, 1591, 1716, 1585, 1510, 1716, 1590, 1509, 1585, 1574, 1500, 1590, 1716, 1512, 1592, 1717, 1595, 1595, 1717, 1593, 1514, 1717, 1594, 1515, 1593, 1592, 1513, 1594, 1717, 1518, 1606, 1718, 1602, 1602, 1718, 1592, 1512, 1718, 1601, 1513, 1592, 1606, 1517, 1601, 1718, 1516, 1605, 1719, 1596, 1596, 1719, 1593, 1515, 1719, 1599, 1514, 1593, 1605, 1519, 1599, 1719, 1519, 1604, 1720, 1599, 1599, 1720, 1595, 1514, 1720, 1602, 1512, 1595, 1604, 1518, 1602, 1720, 1508, 1589, 1721, 1603, 1603, 1721, 1604, 1519, 1721, 1598, 1518, 1604, 1589, 1501, 1598, 1721, 1511, 1586, 1722, 1600, 1600, 1722, 1605, 1516, 1722, 1603, 1519, 1605, 1586, 1508, 1603, 1722, 1501, 1575, 1723, 1598, 1598, 1723, 1606, 1518, 1723, 1597, 1517, 1606, 1575, 1503, 1597, 1723, 1503, 1588, 1724, 1597, 1597, 1724, 1607, 1517, 1724, 1600, 1516, 1607, 1588, 1511, 1600, 1724, 1524, 1614, 1725, 1619, 1619, 1725, 1608, 1525, 1725, 1609, 1521, 1608, 1614, 1520, 1609, 1725, 1537, 1637, 1726, 1643, 1643, 1726, 1632, 1536, 1726, 1630, 1535, 1632, 1637, 1533, 1630, 1726, 1523, 1615, 1727, 1611, 1611, 1727, 1608, 1521, 1727, 1616, 1525, 1608, 1615, 1527, 1616, 1727, 1520, 1614, 1728, 1610, 1610, 1728, 1613, 1522, 1728, 1617, 1526, 1613, 1614, 1524, 1617, 1728, 1522, 1613, 1729, 1612, 1612, 1729, 1615, 1523, 1729, 1618, 1527, 1615, 1613, 1526, 1618, 1729, 1529, 1621, 1730, 1620, 1620, 1730, 1622, 1528, 1730, 1623, 1531, 1622, 1621, 1530, 1623, 1730, 1520, 1626, 1731, 1609, 1609, 1731, 1625, 1521, 1731, 1620, 1528, 1625, 1626, 1529, 1620, 1731, 1523, 1624, 1732, 1612, 1612, 1732, 1627, 1522, 1732, 1623, 1530, 1627, 1624, 1531, 1623, 1732, 1522, 1627, 1733, 1610, 1610, 1733, 1626, 1520, 1733, 1621, 1529, 1626, 1627, 1530, 1621, 1733, 1532, 1631, 1734, 1628, 1628, 1734, 1630, 1533, 1734, 1629, 1535, 1630, 1631, 1534, 1629, 1734, 1538, 1638, 1735, 1642, 1642, 1735, 1637, 1537, 1735, 1628, 1533, 1637, 1638, 1532, 1628, 1735, 1536, 1632, 1736, 1641, 1641, 1736, 1635, 1539, 1736, 1629, 1534, 1635, 1632, 1535, 1629, 1736, 1539, 1635, 1737, 1640, 1640, 1737, 1638, 1538, 1737, 1631, 1532, 1638, 1635, 1534, 1631, 1737, 1528, 1639, 1738, 1625, 1625, 1738, 1634, 1521, 1738, 1640, 1538, 1634, 1639, 1539, 1640, 1738, 1531, 1636, 1739, 1622, 1622, 1739, 1639, 1528, 1739, 1641, 1539, 1639, 1636, 1536, 1641, 1739, 1521, 1634, 1740, 1611, 1611, 1740, 1633, 1523, 1740, 1642, 1537, 1633, 1634, 1538, 1642, 1740, 1523, 1633, 1741, 1624, 1624, 1741, 1636, 1531, 1741, 1643, 1536, 1636, 1633, 1537, 1643, 1741, 1504, 1650, 1742, 1583, 1583, 1742, 1644, 1505, 1742, 1645, 1541, 1644, 1650, 1540, 1645, 1742, 1553, 1669, 1743, 1675, 1675, 1743, 1664, 1552, 1743, 1662, 1551, 1664, 1669, 1549, 1662, 1743, 1543, 1651, 1744, 1647, 1647, 1744, 1644, 1541, 1744, 1580, 1505, 1644, 1651, 1507, 1580, 1744, 1540, 1650, 1745, 1646, 1646, 1745, 1649, 1542, 1745, 1581, 1506, 1649, 1650, 1504, 1581, 1745, 1542, 1649, 1746, 1648, 1648, 1746, 1651, 1543, 1746, 1582, 1507, 1651, 1649, 1506, 1582, 1746, 1545, 1653, 1747, 1652, 1652, 1747, 1654, 1544, 1747, 1655, 1547, 1654, 1653, 1546, 1655, 1747, 1540, 1658, 1748, 1645, 1645, 1748, 1657, 1541, 1748, 1652, 1544, 1657, 1658, 1545, 1652, 1748, 1543, 1656, 1749, 1648, 1648, 1749, 1659, 1542, 1749, 1655, 1546, 1659, 1656, 1547, 1655, 1749, 1542, 1659, 1750, 1646, 1646, 1750, 1658, 1540, 1750, 1653, 1545, 1658, 1659, 1546, 1653, 1750, 1548, 1663, 1751, 1660, 1660, 1751, 1662, 1549, 1751, 1661, 1551, 1662, 1663, 1550, 1661, 1751, 1554, 1670, 1752, 1674, 1674, 1752, 1669, 1553, 1752, 1660, 1549, 1669, 1670, 1548, 1660, 1752, 1552, 1664, 1753, 1673, 1673, 1753, 1667, 1555, 1753, 1661, 1550, 1667, 1664, 1551, 1661, 1753, 1555, 1667, 1754, 1672, 1672, 1754, 1670, 1554, 1754, 1663, 1548, 1670, 1667, 1550, 1663, 1754, 1544, 1671, 1755, 1657, 1657, 1755, 1666, 1541, 1755, 1672, 1554, 1666, 1671, 1555, 1672, 1755, 1547, 1668, 1756, 1654, 1654, 1756, 1671, 1544, 1756, 1673, 1555, 1671, 1668, 1552, 1673, 1756, 1541, 1666, 1757, 1647, 1647, 1757, 1665, 1543, 1757, 1674, 1553, 1665, 1666, 1554, 1674, 1757, 1543, 1665, 1758, 1656, 1656, 1758, 1668, 1547, 1758, 1675, 1552, 1668, 1665, 1553, 1675, 1758, 1524, 1619, 1759, 1682, 1682, 1759, 1677, 1556, 1759, 1676, 1557, 1677, 1619, 1525, 1676, 1759, 1569, 1707, 1760, 1701, 1701, 1760, 1694, 1565, 1760, 1696, 1567, 1694, 1707, 1568, 1696, 1760, 1559, 1679, 1761, 1683, 1683, 1761, 1616, 1527, 1761, 1676, 1525, 1616, 1679, 1557, 1676, 1761, 1556, 1678, 1762, 1682, 1682, 1762, 1617, 1524, 1762, 1681, 1526, 1617, 1678, 1558, 1681, 1762, 1558, 1680, 1763, 1681, 1681, 1763, 1618, 1526, 1763, 1683, 1527, 1618, 1680, 1559, 1683, 1763, 1561, 1684, 1764, 1685, 1685, 1764, 1687, 1562, 1764, 1686, 1563, 1687, 1684, 1560, 1686, 1764, 1556, 1677, 1765, 1690, 1690, 1765, 1684, 1561, 1765, 1689, 1560, 1684, 1677, 1557, 1689, 1765, 1559, 1680, 1766, 1688, 1688, 1766, 1687, 1563, 1766, 1691, 1562, 1687, 1680, 1558, 1691, 1766, 1558, 1678, 1767, 1691, 1691, 1767, 1685, 1562, 1767, 1690, 1561, 1685, 1678, 1556, 1690, 1767, 1564, 1692, 1768, 1695, 1695, 1768, 1693, 1566, 1768, 1694, 1567, 1693, 1692, 1565, 1694, 1768, 1570, 1706, 1769, 1702, 1702, 1769, 1692, 1564, 1769, 1701, 1565, 1692, 1706, 1569, 1701, 1769, 1568, 1705, 1770, 1696, 1696, 1770, 1693, 1567, 1770, 1699, 1566, 1693, 1705, 1571, 1699, 1770, 1571, 1704, 1771, 1699, 1699, 1771, 1695, 1566, 1771, 1702, 1564, 1695, 1704, 1570, 1702, 1771, 1560, 1689, 1772, 1703, 1703, 1772, 1704, 1571, 1772, 1698, 1570, 1704, 1689, 1557, 1698, 1772, 1563, 1686, 1773, 1700, 1700, 1773, 1705, 1568, 1773, 1703, 1571, 1705, 1686, 1560, 1703, 1773, 1557, 1679, 1774, 1698, 1698, 1774, 1706, 1570, 1774, 1697, 1569, 1706, 1679, 1559, 1697, 1774, 1559, 1688, 1775, 1697, 1697, 1775, 1707, 1569, 1775, 1700, 1568, 1707, 1688, 1563, 1700, 1775
-            );
-        let example_mesh_counts: Vec<usize> = vec!(
-            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4
-            );
-
         egui::CentralPanel::default().show(ctx, |ui| {
-            let camera = camera::Perspective::new(0.35, self.camera_xform, 1280.0/720.0, 0.01, 30.0);
-            
-            
-            // add all shapes we wanna render
-            let scene: Vec<Box<dyn shapes::Shape3d>> = vec!(
-                Box::new( shapes::grid::Grid::new(1.0, Mat4::IDENTITY, 0.04) ),
-                
-                // BOUNDING BOXES / CUBES
-                Box::new( shapes::cube::Cube::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 0.0, -4.0)) * Mat4::from_scale(Vec3::new(1.5, 0.2, 1.5)), 
-                    Vec3::ONE,
-                    2.0,
-                    egui::Color32::LIGHT_GRAY,
-                    )),
-                Box::new( shapes::cube::Cube::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 1.0, -4.0)) * Mat4::from_scale(Vec3::new(0.5, 0.5, 0.5)), 
-                    Vec3::ONE,
-                    2.0,
-                    egui::Color32::LIGHT_GRAY,
-                    )),
-                Box::new( shapes::cube::Cube::new(
-                    None, 
-                    Mat4::from_translation(Vec3::new(0.0, 0.5, -2.0)), 
-                    Vec3::ONE,
-                    2.0,
-                    egui::Color32::LIGHT_GRAY,
-                    )),
-                Box::new( shapes::cube::Cube::new(
-                    Some("Cube1".into()),
-                    Mat4::from_translation(Vec3::new(0.0, 0.5, 10.0)), 
-                    Vec3::ONE,
-                    2.0,
-                    egui::Color32::LIGHT_GREEN,
-                    )),
-                Box::new( shapes::cube::Cube::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(1.0, 1.5, -2.0)) * Mat4::from_rotation_x(30_f32.to_radians()), 
-                    Vec3::ONE,
-                    2.0,
-                    egui::Color32::LIGHT_GRAY,
-                    )),
-                
-                // POINT LIGHT
-                Box::new( shapes::point_light::PointLight::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 4.5, 0.0)) * Mat4::from_rotation_x(30_f32.to_radians()),
-                    0.3,
-                    egui::Color32::LIGHT_YELLOW,
-                    ) ),
-                
-                // VECTOR ARROWS
-                Box::new( shapes::vector::Vector::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 0.0, 4.0)),
-                    1.0,
-                    egui::Color32::RED,
-                    Vec3::new(1.0, 0.0, 0.0).normalize()
-                    ) ),
-                Box::new( shapes::vector::Vector::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 0.0, 4.0)),
-                    1.0,
-                    egui::Color32::GREEN,
-                    Vec3::new(0.0, 1.0, 0.0).normalize()
-                    ) ),
-                Box::new( shapes::vector::Vector::new(
-                    None,
-                    Mat4::from_translation(Vec3::new(0.0, 0.0, 4.0)),
-                    1.0,
-                    egui::Color32::BLUE,
-                    Vec3::new(0.0, 0.0, 1.0).normalize()
-                    ) ),
-                
-                // POINTCLOUD
-                Box::new( shapes::point_cloud::PointCloud::new(
-                    Some("pointcloud1".into()),
-                    Mat4::from_translation(Vec3::new(-0.0, 0.5, 2.0)) * Mat4::from_rotation_x(30_f32.to_radians()),
-                    0.02,
-                    egui::Color32::LIGHT_GREEN,
-                    example_pointcloud, 
-                    ) ),
-                
-                // POLYMESH
-                Box::new( shapes::polymesh::Polymesh::new(
-                    Some("Teapot".into()),
-                    Mat4::from_translation(Vec3::new(-0.0, 0.5, 0.0)) * Mat4::from_scale(Vec3::new(10.0, 10.0, 10.0)),
-                    2.0,
-                    egui::Color32::LIGHT_RED,
-                    example_mesh_points,
-                    example_mesh_indices,
-                    example_mesh_counts,
-                    ) ),
-                 );     
-            
-            let view_response = ui.add(
-                Viewport3d::default()
-                .with_scene(scene)
-                .with_camera(Box::new(camera))
+            let view_response = Viewport3d::new(&self.scene, &self.camera)
+                .with_controller(CameraController::new(NavigationPreset::Maya))
+                .with_depth_sort(DepthSort::PerPrimitive)
+                .with_selection(&mut self.selection)
                 .with_size(1280, 720)
-            );
-                
-            // handle camera rotation by mouse dragging 
-            if ctx.input(|i|{ i.modifiers.alt && !i.pointer.middle_down()}){
-                let cam_rot_y = Mat4::from_rotation_y(view_response.drag_delta().x * self.sensitivity);
-                let x_axis = self.camera_xform.x_axis.truncate();
-                let cam_rot_x = Mat4::from_axis_angle(x_axis, view_response.drag_delta().y * self.sensitivity);
-                self.camera_xform = cam_rot_y * cam_rot_x * camera.xform;
-            }
-            
-            if ctx.input(|i|{ i.modifiers.alt && i.pointer.middle_down()}){
-                let drag = view_response.drag_delta();
-                let pan_m_x = Mat4::from_translation(self.camera_xform.x_axis.truncate() * -drag.x * self.sensitivity);
-                let pan_m_y = Mat4::from_translation(self.camera_xform.y_axis.truncate() * -drag.y * self.sensitivity);
-                self.camera_xform = pan_m_x * pan_m_y * self.camera_xform ;
-            }
-
+                .show(ui);
             
+            ui.horizontal(|ui| {
+                match &view_response.hovered {
+                    Some(pick) => ui.label(format!("hovered {} at {:.2?}", pick.name, pick.position)),
+                    None => ui.label("hovered nothing"),
+                };
+                ui.separator();
+                ui.label(format!("{} selected", self.selection.len()));
+            });
             
-            // handle camera dolly by scrolling and paning 
+            // move the selected node around, W / E / R switch between translate, rotate and scale like in Maya
             ctx.input(|i|{
-                //dolly
-                let scroll = i.scroll_delta.y;
-                if scroll != 0.0{
-                    let dolly_center = Vec3::ZERO;
-                    let z_axis = (self.camera_xform.transform_point3(Vec3::ZERO)-dolly_center).normalize();//self.camera_xform.z_axis.truncate();
-                    let dolly_m = Mat4::from_translation(z_axis * scroll * self.sensitivity);
-                    self.camera_xform = dolly_m * self.camera_xform ;
-                    }
+                if i.key_pressed(egui::Key::W) { self.gizmo_mode = GizmoMode::Translate; }
+                if i.key_pressed(egui::Key::E) { self.gizmo_mode = GizmoMode::Rotate; }
+                if i.key_pressed(egui::Key::R) { self.gizmo_mode = GizmoMode::Scale; }
             });
-
-            
-            
+            if let Some(selected) = self.selection.iter().next() {
+                let parent_xform = self.scene.node(selected).and_then(|n| n.parent()).map(|p| self.scene.world_xform(p)).unwrap_or(Mat4::IDENTITY);
+                if let Some(node) = self.scene.node_mut(selected) {
+                    Gizmo::new(&mut node.xform)
+                        .with_parent(parent_xform)
+                        .with_mode(self.gizmo_mode)
+                        .with_space(GizmoSpace::Local)
+                        .interact(ui, &view_response);
+                }
+            }
+                
             ui.separator();
+            ui.add(Legend::new(pointcloud_colors(), (0.0, 1.0)).with_label("pointcloud height"));
 
 
         });
     }
 }
 
+fn pointcloud_colors() -> ScalarMap {
+    ScalarMap::new(Colormap::Viridis).with_range(0.0, 1.0)
+}
 
-
+// builds the scene once, the viewport only borrows it every frame
+fn example_scene() -> Scene {
+    //creating some points we will use later in poincloud shape
+    let example_pointcloud: Vec<Vec3> = (0..90).into_iter().map(|pi| {let u = pi as f32/90.0; Vec3::new((u*10.0).sin(), (u*10.0).cos(), u) }).collect();
+    
+    let example_heights: Vec<f32> = example_pointcloud.iter().map(|p| p.z).collect();
+    let mut teapot = egui_draw3d::loaders::obj::read_obj(&include_bytes!("teapot.obj")[..]).expect("teapot.obj is valid").remove(0);
+    teapot.xform = Mat4::from_translation(Vec3::new(-0.0, 0.5, 0.0)) * Mat4::from_scale(Vec3::new(10.0, 10.0, 10.0));
+    teapot.line_width = 2.0;
+    teapot.color = egui::Color32::LIGHT_RED;
+
+    // add all shapes we wanna render
+    let scene: Vec<Box<dyn shapes::Shape3d>> = vec!(
+        Box::new( shapes::grid::Grid::new(1.0, Mat4::IDENTITY, 0.04) ),
+        
+        // BOUNDING BOXES / CUBES
+        Box::new( shapes::cube::Cube::new(
+            None,
+            Mat4::from_translation(Vec3::new(0.0, 0.0, -4.0)) * Mat4::from_scale(Vec3::new(1.5, 0.2, 1.5)), 
+            Vec3::ONE,
+            2.0,
+            egui::Color32::LIGHT_GRAY,
+            )),
+        Box::new( shapes::cube::Cube::new(
+            None,
+            Mat4::from_translation(Vec3::new(0.0, 1.0, -4.0)) * Mat4::from_scale(Vec3::new(0.5, 0.5, 0.5)), 
+            Vec3::ONE,
+            2.0,
+            egui::Color32::LIGHT_GRAY,
+            )),
+        Box::new( shapes::cube::Cube::new(
+            None, 
+            Mat4::from_translation(Vec3::new(0.0, 0.5, -2.0)), 
+            Vec3::ONE,
+            2.0,
+            egui::Color32::LIGHT_GRAY,
+            )),
+        Box::new( shapes::cube::Cube::new(
+            Some("Cube1".into()),
+            Mat4::from_translation(Vec3::new(0.0, 0.5, 10.0)), 
+            Vec3::ONE,
+            2.0,
+            egui::Color32::LIGHT_GREEN,
+            ).with_display(shapes::DisplayMode::Shaded)),
+        
+        // SPHERE
+        Box::new( shapes::sphere::Sphere::new(
+            Some("Sphere".into()),
+            Mat4::from_translation(Vec3::new(3.0, 0.5, 0.0)),
+            0.5,
+            1.0,
+            egui::Color32::LIGHT_BLUE,
+            ).with_display(shapes::DisplayMode::Shaded)),
+        Box::new( shapes::cube::Cube::new(
+            None,
+            Mat4::from_translation(Vec3::new(1.0, 1.5, -2.0)) * Mat4::from_rotation_x(30_f32.to_radians()), 
+            Vec3::ONE,
+            2.0,
+            egui::Color32::LIGHT_GRAY,
+            )),
+        
+        // POINT LIGHT
+        Box::new( shapes::point_light::PointLight::new(
+            None,
+            Mat4::from_translation(Vec3::new(0.0, 4.5, 0.0)) * Mat4::from_rotation_x(30_f32.to_radians()),
+            0.3,
+            egui::Color32::LIGHT_YELLOW,
+            ) ),
+        Box::new( shapes::directional_light::DirectionalLight::new(
+            Some("sun".into()),
+            Mat4::from_translation(Vec3::new(-4.0, 4.0, 0.0)) * Mat4::from_rotation_x(-60_f32.to_radians()),
+            1.0,
+            egui::Color32::WHITE,
+            ).with_intensity(0.4) ),
+        Box::new( shapes::spot_light::SpotLight::new(
+            Some("spot".into()),
+            Mat4::from_translation(Vec3::new(4.0, 3.0, 2.0)) * Mat4::from_rotation_x(-45_f32.to_radians()),
+            1.5,
+            egui::Color32::WHITE,
+            20.0,
+            30.0,
+            ).with_intensity(0.6) ),
+        Box::new( shapes::area_light::AreaLight::new(
+            Some("softbox".into()),
+            Mat4::from_translation(Vec3::new(-4.0, 2.0, 3.0)) * Mat4::from_rotation_y(-45_f32.to_radians()),
+            shapes::area_light::AreaLightShape::Rect { width: 1.5, height: 1.0 },
+            egui::Color32::WHITE,
+            ).with_intensity(0.4) ),
+        
+        // POINTCLOUD
+        Box::new( shapes::point_cloud::PointCloud::new(
+            Some("pointcloud1".into()),
+            Mat4::from_translation(Vec3::new(-0.0, 0.5, 2.0)) * Mat4::from_rotation_x(30_f32.to_radians()),
+            0.02,
+            egui::Color32::LIGHT_GREEN,
+            example_pointcloud.clone(), 
+            )
+            // colored by height and growing along the spiral
+            .with_scalars(&example_heights, &pointcloud_colors())
+            .with_sizes((0..90).map(|pi| 0.01 + 0.03 * pi as f32 / 90.0).collect()) ),
+        
+        // POLYMESH
+        Box::new( teapot.with_display(shapes::DisplayMode::ShadedWireframe) ),
+         );
+    let mut scene: Scene = scene.into();
+    
+    // VECTOR ARROWS, grouped under one parent node
+    let axes = scene.add_node(None, Node::new("axes").with_xform(Mat4::from_translation(Vec3::new(0.0, 0.0, 4.0))));
+    for (name, color, dir) in [("x", egui::Color32::RED, Vec3::X), ("y", egui::Color32::GREEN, Vec3::Y), ("z", egui::Color32::BLUE, Vec3::Z)]{
+        scene.add_node(Some(axes), Node::new(name).with_shape(Box::new( shapes::vector::Vector::new(
+            None,
+            Mat4::IDENTITY,
+            1.0,
+            color,
+            dir
+            ) )));
+    }
+    scene
+}
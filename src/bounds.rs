@@ -0,0 +1,62 @@
+use glam::{Vec3, Mat4};
+
+// axis aligned bounding box
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3)->Self{
+        Aabb { min, max }
+    }
+    // box around a point, grown by `radius` in every direction
+    pub fn around(center: Vec3, radius: f32)->Self{
+        Aabb::new(center - Vec3::splat(radius), center + Vec3::splat(radius))
+    }
+    // box around all points, None if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>) -> Option<Self>{
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb::new(first, first), |b, p| Aabb::new(b.min.min(p), b.max.max(p))))
+    }
+    pub fn union(&self, other: &Aabb) -> Aabb{
+        Aabb::new(self.min.min(other.min), self.max.max(other.max))
+    }
+    pub fn expanded(&self, amount: f32) -> Aabb{
+        Aabb::new(self.min - Vec3::splat(amount), self.max + Vec3::splat(amount))
+    }
+    pub fn center(&self) -> Vec3{
+        (self.min + self.max) / 2.0
+    }
+    pub fn size(&self) -> Vec3{
+        self.max - self.min
+    }
+    pub fn contains(&self, p: Vec3) -> bool{
+        p.cmpge(self.min).all() && p.cmple(self.max).all()
+    }
+    // squared distance from p to the closest point of the box, 0 inside
+    pub fn distance_squared(&self, p: Vec3) -> f32{
+        (p - p.clamp(self.min, self.max)).length_squared()
+    }
+    // ray parameter where the ray enters the box, 0 if it starts inside, None if it misses
+    pub fn ray(&self, origin: Vec3, dir: Vec3) -> Option<f32>{
+        let inv = dir.recip();
+        let t1 = (self.min - origin) * inv;
+        let t2 = (self.max - origin) * inv;
+        let t_min = t1.min(t2).max_element().max(0.0);
+        let t_max = t1.max(t2).min_element();
+        (t_min <= t_max).then_some(t_min)
+    }
+    pub fn corners(&self) -> [Vec3; 8]{
+        let (a, b) = (self.min, self.max);
+        [
+            Vec3::new(a.x, a.y, a.z), Vec3::new(b.x, a.y, a.z), Vec3::new(a.x, b.y, a.z), Vec3::new(b.x, b.y, a.z),
+            Vec3::new(a.x, a.y, b.z), Vec3::new(b.x, a.y, b.z), Vec3::new(a.x, b.y, b.z), Vec3::new(b.x, b.y, b.z),
+        ]
+    }
+    // box around the transformed corners, stays axis aligned so it can get bigger than the shape inside
+    pub fn transformed(&self, xform: Mat4) -> Aabb{
+        Aabb::from_points(self.corners().map(|c| xform.transform_point3(c))).unwrap()
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::Range;
+
+use glam::{Vec3, Mat4};
+
+use crate::bounds::Aabb;
+use crate::clipping::{self, ClipPlanes};
+
+const LEAF_SIZE: usize = 4; // most items a leaf holds
+
+#[derive(Clone, Debug)]
+pub struct BvhNode {
+    pub bounds: Aabb, // box around all items of the node
+    pub items: Range<usize>, // its items, a range of Bvh::items
+    pub children: Range<usize>, // the two halves, a range of Bvh::nodes, empty for leaves
+}
+impl BvhNode {
+    pub fn is_leaf(&self) -> bool{
+        self.children.is_empty()
+    }
+}
+
+// Bounding volume hierarchy over items that only need a box each, e.g. the faces of a polymesh. The queries
+// hand the candidate items to a callback for the exact test, so the hierarchy doesn't need to know what they are
+#[derive(Clone, Debug)]
+pub struct Bvh {
+    pub nodes: Vec<BvhNode>, // root first
+    pub items: Vec<usize>, // item indices ordered so every node covers a contiguous range
+}
+impl Bvh {
+    pub fn new(item_bounds: &[Aabb])->Self{
+        let mut bvh = Bvh {
+            nodes: Vec::new(),
+            items: (0..item_bounds.len()).collect(),
+        };
+        if item_bounds.is_empty() { return bvh; }
+        bvh.nodes.push(Self::node(item_bounds, &bvh.items, 0..item_bounds.len()));
+        bvh.split(item_bounds, 0);
+        bvh
+    }
+    pub fn len(&self) -> usize{
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.items.is_empty()
+    }
+    pub fn bounds(&self) -> Option<Aabb>{
+        self.nodes.first().map(|root| root.bounds)
+    }
+
+    fn node(item_bounds: &[Aabb], items: &[usize], range: Range<usize>) -> BvhNode{
+        let bounds = items[range.clone()].iter().skip(1).fold(item_bounds[items[range.start]], |b, i| b.union(&item_bounds[*i]));
+        BvhNode { bounds, items: range, children: 0..0 }
+    }
+    // median split along the axis the item centers spread the most
+    fn split(&mut self, item_bounds: &[Aabb], node: usize){
+        let range = self.nodes[node].items.clone();
+        if range.len() <= LEAF_SIZE { return; }
+        let Some(centers) = Aabb::from_points(self.items[range.clone()].iter().map(|i| item_bounds[*i].center())) else { return; };
+        let size = centers.size();
+        let axis = if size.x >= size.y && size.x >= size.z { 0 } else if size.y >= size.z { 1 } else { 2 };
+        if size[axis].is_nan() || size[axis] <= 0.0 { return; } // all items in the same spot, or not a number
+        let mid = range.len() / 2;
+        self.items[range.clone()].select_nth_unstable_by(mid, |a, b| item_bounds[*a].center()[axis].total_cmp(&item_bounds[*b].center()[axis]));
+
+        let first_child = self.nodes.len();
+        self.nodes.push(Self::node(item_bounds, &self.items, range.start..range.start + mid));
+        self.nodes.push(Self::node(item_bounds, &self.items, range.start + mid..range.end));
+        self.nodes[node].children = first_child..first_child + 2;
+        self.split(item_bounds, first_child);
+        self.split(item_bounds, first_child + 1);
+    }
+
+    // Closest hit along the ray. `hit` intersects a single item and returns the ray parameter, boxes further
+    // away than the closest hit so far are skipped
+    pub fn ray(&self, origin: Vec3, dir: Vec3, mut hit: impl FnMut(usize) -> Option<f32>) -> Option<(usize, f32)>{
+        let mut closest: Option<(usize, f32)> = None;
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            let Some(t_enter) = node.bounds.ray(origin, dir) else { continue; };
+            if closest.is_some_and(|(_, t)| t_enter > t) { continue; }
+            if node.is_leaf() {
+                for item in self.items[node.items.clone()].iter() {
+                    if let Some(t) = hit(*item) {
+                        if closest.map_or(true, |(_, closest_t)| t < closest_t) { closest = Some((*item, t)); }
+                    }
+                }
+            } else {
+                stack.extend(node.children.clone());
+            }
+        }
+        closest
+    }
+
+    // items whose boxes reach into the view frustum of `view_projection`, in the order of the hierarchy
+    pub fn frustum(&self, view_projection: &Mat4) -> Vec<usize>{
+        let mut found = Vec::new();
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            let corners = node.bounds.corners().map(|c| clipping::to_clip(view_projection, c));
+            if ClipPlanes::Frustum.excludes(&corners) { continue; }
+            if node.is_leaf() || corners.iter().all(|c| ClipPlanes::Frustum.contains(*c)) {
+                found.extend_from_slice(&self.items[node.items.clone()]);
+            } else {
+                stack.extend(node.children.clone().rev());
+            }
+        }
+        found
+    }
+
+    // Item closest to p. `distance_squared` measures a single item, boxes further away than the closest item
+    // so far are skipped
+    pub fn nearest(&self, p: Vec3, mut distance_squared: impl FnMut(usize) -> f32) -> Option<(usize, f32)>{
+        let mut closest: Option<(usize, f32)> = None;
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            if closest.is_some_and(|(_, d)| node.bounds.distance_squared(p) > d) { continue; }
+            if node.is_leaf() {
+                for item in self.items[node.items.clone()].iter() {
+                    let d = distance_squared(*item);
+                    if closest.map_or(true, |(_, closest_d)| d < closest_d) { closest = Some((*item, d)); }
+                }
+            } else {
+                // visit the closer half first so the other one is more likely to get skipped
+                let mut children: Vec<usize> = node.children.clone().collect();
+                children.sort_by(|a, b| self.nodes[*b].bounds.distance_squared(p).total_cmp(&self.nodes[*a].bounds.distance_squared(p)));
+                stack.extend(children);
+            }
+        }
+        closest.map(|(item, d)| (item, d.sqrt()))
+    }
+}
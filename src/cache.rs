@@ -0,0 +1,31 @@
+use std::sync::{Arc, Mutex};
+
+// Acceleration structure built lazily from the geometry of a shape. The shapes keep their geometry private
+// and invalidate the cache whenever they hand it out for editing
+pub(crate) struct GeometryCache<T> {
+    built: Mutex<Option<Arc<T>>>,
+}
+impl<T> GeometryCache<T> {
+    pub fn new()->Self{
+        GeometryCache { built: Mutex::new(None) }
+    }
+    pub fn get(&self, build: impl FnOnce() -> T) -> Arc<T>{
+        let mut built = self.built.lock().unwrap_or_else(|e| e.into_inner());
+        built.get_or_insert_with(|| Arc::new(build())).clone()
+    }
+    // the next get builds again
+    pub fn invalidate(&mut self){
+        *self.built.get_mut().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+impl<T> Clone for GeometryCache<T> {
+    fn clone(&self) -> Self {
+        let built = self.built.lock().unwrap_or_else(|e| e.into_inner());
+        GeometryCache { built: Mutex::new(built.clone()) }
+    }
+}
+impl<T> Default for GeometryCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
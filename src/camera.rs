@@ -1,48 +1,195 @@
+use std::borrow::Cow;
+
 use glam::{Vec3, Mat4};
 
+use crate::clipping;
+
 pub trait Camera {
-    fn project_point(&self, p: Vec3) -> Vec3;
+    fn view_projection(&self) -> Mat4; // world space to homogeneous clip space
+    // projects to -0.5..0.5 over the frame with the 0..1 depth in z, NAN if the point is in front of the near plane
+    fn project_point(&self, p: Vec3) -> Vec3{
+        let p_clip = clipping::to_clip(&self.view_projection(), p);
+        if p_clip.z < 0.0 {return Vec3::NAN;}
+        clipping::clip_to_projected(p_clip)
+    }
+    // inverse of project_point, takes -0.5..0.5 frame coordinates and the 0..1 depth back to world space
+    fn unproject(&self, p: Vec3) -> Vec3{
+        let ndc = Vec3::new(p.x * 2.0, p.y * 2.0, p.z);
+        self.view_projection().inverse().project_point3(ndc)
+    }
+    // world space ray going through a screen position of the viewport, the origin is on the near plane and the direction is normalized
+    fn screen_ray(&self, pos: egui::Pos2, viewport_rect: egui::Rect) -> (Vec3, Vec3){
+        let frame_pos = (pos - viewport_rect.center()) / viewport_rect.size();
+        let near = self.unproject(Vec3::new(frame_pos.x, frame_pos.y, 0.0));
+        let far = self.unproject(Vec3::new(frame_pos.x, frame_pos.y, 1.0));
+        (near, (far - near).normalize())
+    }
+    // normalized direction from p back to the viewer along the line of sight through p, towards the eye of a
+    // perspective camera and the same for every point of an orthographic one
+    fn to_viewer(&self, p: Vec3) -> Vec3{
+        let p_projected = clipping::clip_to_projected(clipping::to_clip(&self.view_projection(), p));
+        let near = self.unproject(Vec3::new(p_projected.x, p_projected.y, 0.0));
+        let far = self.unproject(Vec3::new(p_projected.x, p_projected.y, 1.0));
+        (near - far).normalize_or_zero()
+    }
     fn dist_to_point(&self, p: Vec3) -> f32;
     fn get_center(&self) -> Vec3;
     fn get_xform(&self) -> Mat4;
+    // x axis of the camera in world space, screen sizes of things get measured along it
+    fn get_right(&self) -> Vec3{
+        self.get_xform().x_axis.truncate() * Vec3::new(1.0, -1.0, 1.0) // the xform lives in a Y flipped space
+    }
     fn get_far(&self) -> f32;
+    fn set_aspect_ratio(&mut self, aspect_ratio: f32); // called by the viewport with the aspect ratio of its rect
+    fn set_xform(&mut self, xform: Mat4);
+    // Zoom of cameras where moving closer doesn't make things bigger on screen, like the orthographic one, 2 shows
+    // everything twice as big. None for cameras that don't zoom, navigation dollies those instead
+    fn get_zoom(&self) -> Option<f32>{
+        None
+    }
+    fn set_zoom(&mut self, _zoom: f32){}
+    fn box_clone(&self) -> Box<dyn Camera>; // the viewport works on a copy so it can fit it to its rect
 }
+
+// lets a view like ParentSpace borrow a camera and only copy it when it gets cloned itself
+impl<'a> ToOwned for dyn Camera + 'a {
+    type Owned = Box<dyn Camera + 'a>;
+    fn to_owned(&self) -> Box<dyn Camera + 'a>{
+        self.box_clone()
+    }
+}
+
+// how the film back is fitted into the viewport when their aspect ratios differ, same as in Maya / USD
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GateFit {
+    Fill, // the viewport is fitted inside the film back, nothing outside of the film is visible
+    Overscan, // the film back is fitted inside the viewport, everything on the film is visible
+    Horizontal,
+    Vertical,
+}
+
 #[derive(Copy, Clone)]
 pub struct Perspective { 
     pub focal_lenght: f32, 
+    pub horizontal_aperture: f32, // film back, same units as the focal length
+    pub vertical_aperture: f32,
+    pub gate_fit: GateFit,
     pub xform: Mat4,
     pub aspect_ratio :f32,
     pub near: f32,
     pub far: f32,
     }
 impl Perspective  {
-    pub fn new(focal_lenght: f32, xform: Mat4, aspect_ratio: f32, near: f32 , far: f32 )->Self{
+    pub fn new(focal_lenght: f32, xform: Mat4, near: f32 , far: f32 )->Self{
         Perspective { 
-            focal_lenght: focal_lenght, 
-            xform: xform ,
-            aspect_ratio: aspect_ratio,
-            near: near,
-            far: far,
+            focal_lenght, 
+            horizontal_aperture: 0.20955, // 35mm film back with a 1.85:1 flat gate
+            vertical_aperture: 0.113284,
+            gate_fit: GateFit::Fill,
+            xform,
+            aspect_ratio: 1.0,
+            near,
+            far,
+        }
+    }
+    pub fn with_aperture(mut self, horizontal_aperture: f32, vertical_aperture: f32)->Self{
+        self.horizontal_aperture = horizontal_aperture;
+        self.vertical_aperture = vertical_aperture;
+        self
+    }
+    pub fn with_gate_fit(mut self, gate_fit: GateFit)->Self{
+        self.gate_fit = gate_fit;
+        self
+    }
+    
+    // tangents of the horizontal and vertical half angles of view after fitting the film back to the viewport
+    pub fn half_fov_tangents(&self) -> (f32, f32){
+        let film_aspect = self.horizontal_aperture / self.vertical_aperture;
+        let fit_horizontal = match self.gate_fit {
+            GateFit::Horizontal => true,
+            GateFit::Vertical => false,
+            GateFit::Fill => self.aspect_ratio > film_aspect,
+            GateFit::Overscan => self.aspect_ratio < film_aspect,
+        };
+        if fit_horizontal {
+            let tan_x = self.horizontal_aperture / 2.0 / self.focal_lenght;
+            (tan_x, tan_x / self.aspect_ratio)
+        } else {
+            let tan_y = self.vertical_aperture / 2.0 / self.focal_lenght;
+            (tan_y * self.aspect_ratio, tan_y)
         }
     }
+    pub fn projection_matrix(&self) -> Mat4{
+        let (_, tan_y) = self.half_fov_tangents();
+        Mat4::perspective_rh(2.0 * tan_y.atan(), self.aspect_ratio, self.near, self.far)
+    }
     
 }
 
 //camera Y translation has to be flipped to match 
 impl Camera for Perspective {
-    fn project_point(&self, p: Vec3) -> Vec3 
-    {
-        let h_aperature = 0.209549993277;
-        let fov_x: f32 = 2.0 * f32::tan((h_aperature/2.0) / self.focal_lenght); //TODO calculate fov_y by takign aspect ratio into account
-        //println!("fov: {:?}", fov_x);
-        let prj_mtx = Mat4::perspective_rh(fov_x, self.aspect_ratio, self.near, self.far);
-        let p_xformed =  self.xform.inverse().transform_point3(p * Vec3::new(1.0, -1.0, 1.0)); // apply the camera xform
-        if p_xformed.z >= 0.0 {return Vec3::NAN;}
-        let p_projected = prj_mtx.project_point3(p_xformed); // apply the perspective projection
-        if p_projected.z <= self.near{
-            return Vec3::NAN;
+    fn view_projection(&self) -> Mat4{
+        self.projection_matrix() * self.xform.inverse() * Mat4::from_scale(Vec3::new(1.0, -1.0, 1.0))
+    }
+    fn to_viewer(&self, p: Vec3) -> Vec3{
+        (self.get_center() - p).normalize_or_zero()
+    }
+    fn dist_to_point(&self, p: Vec3) -> f32{
+        (self.get_center() - p ).length()
+    }
+    fn get_center(&self) -> Vec3{
+        self.xform.transform_point3(Vec3::ZERO) * Vec3::new(1.0, -1.0, 1.0)
+    }
+    fn get_xform(&self) -> Mat4{
+        self.xform
+    }
+    fn get_far(&self) -> f32{
+        self.far
+    }
+    fn set_aspect_ratio(&mut self, aspect_ratio: f32){
+        self.aspect_ratio = aspect_ratio;
+    }
+    fn set_xform(&mut self, xform: Mat4){
+        self.xform = xform;
+    }
+    fn box_clone(&self) -> Box<dyn Camera>{
+        Box::new(*self)
+    }
+}
+#[derive(Copy, Clone)]
+pub struct Orthographic { 
+    pub ortho_width: f32, // width of the visible area in world units, height follows from the aspect ratio
+    pub zoom: f32, // the visible width is ortho_width / zoom
+    pub xform: Mat4,
+    pub aspect_ratio :f32,
+    pub near: f32,
+    pub far: f32,
+    }
+impl Orthographic  {
+    pub fn new(ortho_width: f32, xform: Mat4, near: f32 , far: f32 )->Self{
+        Orthographic { 
+            ortho_width, 
+            zoom: 1.0,
+            xform,
+            aspect_ratio: 1.0,
+            near,
+            far,
         }
-        p_projected
+    }
+    pub fn projection_matrix(&self) -> Mat4{
+        let half_width = self.ortho_width / self.zoom / 2.0;
+        let half_height = half_width / self.aspect_ratio;
+        Mat4::orthographic_rh(-half_width, half_width, -half_height, half_height, self.near, self.far)
+    }
+    
+}
+
+impl Camera for Orthographic {
+    fn view_projection(&self) -> Mat4{
+        self.projection_matrix() * self.xform.inverse() * Mat4::from_scale(Vec3::new(1.0, -1.0, 1.0))
+    }
+    fn to_viewer(&self, _p: Vec3) -> Vec3{
+        (self.xform.z_axis.truncate() * Vec3::new(1.0, -1.0, 1.0)).normalize_or_zero() // looks down its -z axis
     }
     fn dist_to_point(&self, p: Vec3) -> f32{
         (self.get_center() - p ).length()
@@ -56,4 +203,78 @@ impl Camera for Perspective {
     fn get_far(&self) -> f32{
         self.far
     }
-}
\ No newline at end of file
+    fn set_aspect_ratio(&mut self, aspect_ratio: f32){
+        self.aspect_ratio = aspect_ratio;
+    }
+    fn set_xform(&mut self, xform: Mat4){
+        self.xform = xform;
+    }
+    fn get_zoom(&self) -> Option<f32>{
+        Some(self.zoom)
+    }
+    fn set_zoom(&mut self, zoom: f32){
+        self.zoom = zoom;
+    }
+    fn box_clone(&self) -> Box<dyn Camera>{
+        Box::new(*self)
+    }
+}
+
+// views the scene from inside the space of a parent transform, so a shape with its own local xform
+// can be drawn and picked under a scene graph node without knowing about the hierarchy
+pub struct ParentSpace<'a> {
+    camera: Cow<'a, dyn Camera + 'a>, // borrowed, only owned by a box_clone
+    parent_xform: Mat4,
+    parent_inv: Mat4,
+    view_projection: Mat4, // camera view projection composed with the parent xform
+}
+impl<'a> ParentSpace<'a> {
+    pub fn new(camera: &'a dyn Camera, parent_xform: Mat4)->Self{
+        ParentSpace {
+            camera: Cow::Borrowed(camera),
+            parent_xform,
+            parent_inv: parent_xform.inverse(),
+            view_projection: camera.view_projection() * parent_xform,
+        }
+    }
+    pub fn parent_xform(&self) -> Mat4{
+        self.parent_xform
+    }
+}
+
+// a read only view, the borrowed camera can't be moved or fitted through it
+impl Camera for ParentSpace<'_> {
+    fn view_projection(&self) -> Mat4{
+        self.view_projection
+    }
+    fn to_viewer(&self, p: Vec3) -> Vec3{
+        self.parent_inv.transform_vector3(self.camera.to_viewer(self.parent_xform.transform_point3(p))).normalize_or_zero()
+    }
+    fn dist_to_point(&self, p: Vec3) -> f32{
+        self.camera.dist_to_point(self.parent_xform.transform_point3(p))
+    }
+    fn get_center(&self) -> Vec3{
+        self.parent_inv.transform_point3(self.camera.get_center())
+    }
+    // camera xforms live in a Y flipped space, so the parent inverse gets applied in between the flips
+    fn get_xform(&self) -> Mat4{
+        let flip_y = Mat4::from_scale(Vec3::new(1.0, -1.0, 1.0));
+        flip_y * self.parent_inv * flip_y * self.camera.get_xform()
+    }
+    fn get_far(&self) -> f32{
+        self.camera.get_far()
+    }
+    fn set_aspect_ratio(&mut self, _aspect_ratio: f32){}
+    fn set_xform(&mut self, _xform: Mat4){}
+    fn get_zoom(&self) -> Option<f32>{
+        self.camera.get_zoom()
+    }
+    fn box_clone(&self) -> Box<dyn Camera>{
+        Box::new(ParentSpace {
+            camera: Cow::Owned(self.camera.box_clone()),
+            parent_xform: self.parent_xform,
+            parent_inv: self.parent_inv,
+            view_projection: self.view_projection,
+        })
+    }
+}
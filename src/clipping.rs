@@ -0,0 +1,126 @@
+use glam::{Vec3, Vec4, Mat4};
+
+// which planes of the view frustum geometry gets clipped against, in homogeneous clip space
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ClipPlanes {
+    Near, // only cut away what is behind the camera, lines can still run out of the viewport
+    Frustum, // near, far and the four sides of the frame
+}
+impl ClipPlanes {
+    fn distances(&self) -> &'static [fn(Vec4) -> f32] {
+        const NEAR: [fn(Vec4) -> f32; 1] = [|c| c.z];
+        const FRUSTUM: [fn(Vec4) -> f32; 6] = [
+            |c| c.z, // near, depth goes 0..1
+            |c| c.w - c.z, // far
+            |c| c.w + c.x, // left
+            |c| c.w - c.x, // right
+            |c| c.w + c.y, // bottom
+            |c| c.w - c.y, // top
+        ];
+        match self {
+            ClipPlanes::Near => &NEAR,
+            ClipPlanes::Frustum => &FRUSTUM,
+        }
+    }
+    // true if the clip space point is on the inside of all planes
+    pub fn contains(&self, p: Vec4) -> bool {
+        self.distances().iter().all(|dist| dist(p) >= 0.0)
+    }
+    // true if all clip space points are on the outside of the same plane, e.g. the corners of a box
+    // that can't be seen. Boxes crossing a frustum edge diagonally can still count as inside
+    pub fn excludes(&self, points: &[Vec4]) -> bool {
+        self.distances().iter().any(|dist| points.iter().all(|p| dist(*p) < 0.0))
+    }
+}
+
+// world space point to homogeneous clip space
+pub fn to_clip(view_projection: &Mat4, p: Vec3) -> Vec4 {
+    *view_projection * p.extend(1.0)
+}
+
+// clip space to the projected space shapes work in, the frame spans -0.5..0.5 and z is the 0..1 depth
+pub fn clip_to_projected(c: Vec4) -> Vec3 {
+    Vec3::new(c.x / c.w * 0.5, c.y / c.w * 0.5, c.z / c.w)
+}
+
+// screen position inside a viewport rect and 0..1 depth of a clip space point
+pub fn clip_to_screen(rect: egui::Rect, c: Vec4) -> (egui::Pos2, f32) {
+    let p = clip_to_projected(c);
+    (rect.center() + egui::Vec2::new(p.x * rect.width(), p.y * rect.height()), p.z)
+}
+
+// screen position and depth of a point, None if it is behind the near plane or not a number
+pub fn world_to_screen(view_projection: &Mat4, rect: egui::Rect, p: Vec3) -> Option<(egui::Pos2, f32)> {
+    let c = to_clip(view_projection, p);
+    if !c.is_finite() || c.z < 0.0 || c.w == 0.0 { return None; }
+    Some(clip_to_screen(rect, c))
+}
+
+// screen size in points of a radius around p, measured along `right`, usually the x axis of the camera
+pub fn screen_radius(view_projection: &Mat4, rect: egui::Rect, p: Vec3, right: Vec3, radius: f32) -> f32 {
+    match (world_to_screen(view_projection, rect, p), world_to_screen(view_projection, rect, p + right.normalize_or_zero() * radius)) {
+        (Some((center, _)), Some((edge, _))) => (edge - center).length(),
+        _ => 0.0,
+    }
+}
+
+// cuts the segment at the planes, None if it is completely outside
+pub fn clip_segment(mut a: Vec4, mut b: Vec4, planes: ClipPlanes) -> Option<(Vec4, Vec4)> {
+    for dist in planes.distances() {
+        let (da, db) = (dist(a), dist(b));
+        if da < 0.0 && db < 0.0 { return None; }
+        if da < 0.0 {
+            a = a.lerp(b, da / (da - db));
+        } else if db < 0.0 {
+            b = b.lerp(a, db / (db - da));
+        }
+    }
+    Some((a, b))
+}
+
+// Sutherland-Hodgman clipping of a closed polygon, returns an empty vec if nothing is left
+pub fn clip_polygon(points: &[Vec4], planes: ClipPlanes) -> Vec<Vec4> {
+    let mut poly = points.to_vec();
+    for dist in planes.distances() {
+        if poly.is_empty() { break; }
+        let mut clipped = Vec::with_capacity(poly.len() + 2);
+        for i in 0..poly.len() {
+            let a = poly[i];
+            let b = poly[(i + 1) % poly.len()];
+            let (da, db) = (dist(a), dist(b));
+            if da >= 0.0 { clipped.push(a); }
+            if (da >= 0.0) != (db >= 0.0) {
+                clipped.push(a.lerp(b, da / (da - db)));
+            }
+        }
+        poly = clipped;
+    }
+    poly
+}
+
+// clips an open polyline, a line that leaves and re-enters the clip volume is split into several pieces
+pub fn clip_polyline(points: &[Vec4], planes: ClipPlanes) -> Vec<Vec<Vec4>> {
+    let mut pieces: Vec<Vec<Vec4>> = Vec::new();
+    let mut current: Vec<Vec4> = Vec::new();
+    for pair in points.windows(2) {
+        match clip_segment(pair[0], pair[1], planes) {
+            Some((a, b)) => {
+                if current.is_empty() {
+                    current.push(a);
+                } else if a != pair[0] { // entered the volume again, start a new piece
+                    pieces.push(std::mem::take(&mut current));
+                    current.push(a);
+                }
+                current.push(b);
+                if b != pair[1] { // left the volume
+                    pieces.push(std::mem::take(&mut current));
+                }
+            },
+            None => {
+                if !current.is_empty() { pieces.push(std::mem::take(&mut current)); }
+            },
+        }
+    }
+    if !current.is_empty() { pieces.push(current); }
+    pieces
+}
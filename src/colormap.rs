@@ -0,0 +1,123 @@
+use egui::Color32;
+
+// standard maps from scalar data to color, viridis and magma are perceptually uniform
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    Turbo,
+    Jet,
+}
+
+// matplotlib maps sampled at 0.0, 0.1, .. 1.0
+const VIRIDIS: [[u8; 3]; 11] = [
+    [0x44, 0x01, 0x54], [0x48, 0x24, 0x75], [0x41, 0x44, 0x87], [0x35, 0x5f, 0x8d], [0x2a, 0x78, 0x8e], [0x21, 0x91, 0x8c],
+    [0x22, 0xa8, 0x84], [0x44, 0xbf, 0x70], [0x7a, 0xd1, 0x51], [0xbd, 0xdf, 0x26], [0xfd, 0xe7, 0x25],
+];
+const MAGMA: [[u8; 3]; 11] = [
+    [0x00, 0x00, 0x04], [0x14, 0x0e, 0x36], [0x3b, 0x0f, 0x70], [0x64, 0x1a, 0x80], [0x8c, 0x29, 0x81], [0xb7, 0x37, 0x79],
+    [0xde, 0x49, 0x68], [0xf7, 0x70, 0x5c], [0xfe, 0x9f, 0x6d], [0xfe, 0xcf, 0x92], [0xfc, 0xfd, 0xbf],
+];
+
+impl Colormap {
+    // color at t in 0..1, values outside get clamped
+    pub fn sample(&self, t: f32) -> Color32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let rgb = match self {
+            Colormap::Viridis => Self::lookup(&VIRIDIS, t),
+            Colormap::Magma => Self::lookup(&MAGMA, t),
+            Colormap::Turbo => {
+                // polynomial approximation by Anton Mikhailov
+                let poly = |c: [f32; 6]| c[0] + t * (c[1] + t * (c[2] + t * (c[3] + t * (c[4] + t * c[5]))));
+                [
+                    poly([0.135_721_38, 4.615_392_6, -42.660_323, 132.131_08, -152.942_4, 59.286_38]),
+                    poly([0.091_402_61, 2.194_188_4, 4.842_966_6, -14.185_033, 4.277_298_6, 2.829_566]),
+                    poly([0.106_673_3, 12.641_946, -60.582_047, 110.362_77, -89.903_11, 27.348_25]),
+                ]
+            },
+            Colormap::Jet => [
+                (1.5 - (4.0 * t - 3.0).abs()),
+                (1.5 - (4.0 * t - 2.0).abs()),
+                (1.5 - (4.0 * t - 1.0).abs()),
+            ],
+        };
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color32::from_rgb(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]))
+    }
+
+    fn lookup(table: &[[u8; 3]], t: f32) -> [f32; 3] {
+        let x = t * (table.len() - 1) as f32;
+        let i = (x.floor() as usize).min(table.len() - 2);
+        let f = x - i as f32;
+        let (a, b) = (table[i], table[i + 1]);
+        [0, 1, 2].map(|c| (a[c] as f32 + (b[c] as f32 - a[c] as f32) * f) / 255.0)
+    }
+}
+
+// how values get squeezed into the 0..1 range of a colormap
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Normalization {
+    Linear,
+    Log, // for values spanning several orders of magnitude, values <= 0 map to the bottom of the range
+}
+
+// maps scalar attributes of shapes to colors, e.g. intensity or classification of points or an error per face
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScalarMap {
+    pub colormap: Colormap,
+    pub range: Option<(f32, f32)>, // min and max value, None fits the range to the data
+    pub normalization: Normalization,
+    pub reversed: bool,
+}
+impl ScalarMap {
+    pub fn new(colormap: Colormap)->Self{
+        ScalarMap {
+            colormap,
+            range: None,
+            normalization: Normalization::Linear,
+            reversed: false,
+        }
+    }
+    pub fn with_range(mut self, min: f32, max: f32)->Self{
+        self.range = Some((min, max));
+        self
+    }
+    pub fn with_normalization(mut self, normalization: Normalization)->Self{
+        self.normalization = normalization;
+        self
+    }
+    pub fn with_reversed(mut self, reversed: bool)->Self{
+        self.reversed = reversed;
+        self
+    }
+
+    // the fixed range, or the min and max of the finite values
+    pub fn resolve_range(&self, values: &[f32]) -> (f32, f32) {
+        if let Some(range) = self.range { return range; }
+        values.iter().filter(|v| v.is_finite()).fold(None, |range: Option<(f32, f32)>, v| {
+            Some(range.map_or((*v, *v), |(min, max)| (min.min(*v), max.max(*v))))
+        }).unwrap_or((0.0, 1.0))
+    }
+    // position of the value in the range, 0..1
+    pub fn normalize(&self, value: f32, (min, max): (f32, f32)) -> f32 {
+        let t = match self.normalization {
+            Normalization::Linear => (value - min) / (max - min),
+            Normalization::Log => {
+                let log_min = min.max(f32::MIN_POSITIVE).ln();
+                let log_max = max.max(f32::MIN_POSITIVE).ln();
+                (value.max(f32::MIN_POSITIVE).ln() - log_min) / (log_max - log_min)
+            },
+        };
+        let t = if t.is_finite() { t.clamp(0.0, 1.0) } else { 0.0 }; // a range of a single value maps to the bottom
+        if self.reversed { 1.0 - t } else { t }
+    }
+    // color of a single value, without a fixed range the values are expected in 0..1
+    pub fn color(&self, value: f32) -> Color32 {
+        self.colormap.sample(self.normalize(value, self.range.unwrap_or((0.0, 1.0))))
+    }
+    // colors of all values, fitting the range to them if none is set
+    pub fn colors(&self, values: &[f32]) -> Vec<Color32> {
+        let range = self.resolve_range(values);
+        values.iter().map(|v| self.colormap.sample(self.normalize(*v, range))).collect()
+    }
+}
@@ -0,0 +1,252 @@
+use glam::{Vec3, Vec4, Mat4};
+
+use crate::bounds::Aabb;
+use crate::camera::Camera;
+use crate::clipping::{self, ClipPlanes};
+use crate::draw_list::DrawList;
+use crate::lighting::Lighting;
+use crate::parallel;
+use crate::shapes::{DisplayMode, face_normal};
+
+// settings shared by all shapes drawn into a viewport
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DrawStyle {
+    pub clip_planes: ClipPlanes, // planes lines and faces get cut at
+    pub distance_fade: bool, // fade out and thin shapes the closer they get to the far plane
+}
+impl DrawStyle {
+    pub fn new()->Self{
+        DrawStyle {
+            clip_planes: ClipPlanes::Near,
+            distance_fade: true,
+        }
+    }
+    pub fn with_clip_planes(mut self, clip_planes: ClipPlanes)->Self{
+        self.clip_planes = clip_planes;
+        self
+    }
+    pub fn with_distance_fade(mut self, distance_fade: bool)->Self{
+        self.distance_fade = distance_fade;
+        self
+    }
+}
+
+impl Default for DrawStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Everything a shape needs to draw itself. Points passed in are in the space the shape lives in,
+// the space of its parent node, the context takes care of the parent transform and the projection.
+pub struct DrawContext<'a> {
+    pub camera: &'a dyn Camera, // world space camera, fitted to the viewport
+    pub rect: egui::Rect, // viewport rect on screen
+    pub style: DrawStyle,
+    pub lighting: Lighting, // lights of the scene in world space
+    pub out: &'a mut DrawList,
+    parent_xform: Mat4,
+    parent_inv: Mat4,
+    normal_xform: Mat4, // moves normals from the space of the shape to world space
+    view_projection: Mat4, // includes the parent transform
+    eye: Vec3, // camera position in the space of the shape
+    right: Vec3, // camera x axis in the space of the shape
+}
+impl<'a> DrawContext<'a> {
+    pub fn new(camera: &'a dyn Camera, rect: egui::Rect, out: &'a mut DrawList)->Self{
+        let mut ctx = DrawContext {
+            camera,
+            rect,
+            style: DrawStyle::new(),
+            lighting: Lighting::new(),
+            out,
+            parent_xform: Mat4::IDENTITY,
+            parent_inv: Mat4::IDENTITY,
+            normal_xform: Mat4::IDENTITY,
+            view_projection: Mat4::IDENTITY,
+            eye: Vec3::ZERO,
+            right: Vec3::X,
+        };
+        ctx.set_parent_xform(Mat4::IDENTITY);
+        ctx
+    }
+    pub fn with_style(mut self, style: DrawStyle)->Self{
+        self.style = style;
+        self
+    }
+    // world transform of the node the next shapes are drawn under
+    pub fn set_parent_xform(&mut self, parent_xform: Mat4){
+        let parent_inv = parent_xform.inverse();
+        self.parent_xform = parent_xform;
+        self.parent_inv = parent_inv;
+        self.normal_xform = parent_inv.transpose();
+        self.view_projection = self.camera.view_projection() * parent_xform;
+        self.eye = parent_inv.transform_point3(self.camera.get_center());
+        self.right = parent_inv.transform_vector3(self.camera.get_right());
+    }
+    pub fn parent_xform(&self) -> Mat4{
+        self.parent_xform
+    }
+    pub fn view_projection(&self) -> &Mat4{
+        &self.view_projection
+    }
+    // normalized direction from p back to the viewer in the space of the shape, see Camera::to_viewer
+    pub fn to_viewer(&self, p: Vec3) -> Vec3{
+        let world_dir = self.camera.to_viewer(self.parent_xform.transform_point3(p));
+        self.parent_inv.transform_vector3(world_dir).normalize_or_zero()
+    }
+
+    // false if the bounds are completely outside the view frustum, shapes inside it get drawn
+    pub fn is_visible(&self, bounds: &Aabb) -> bool{
+        !ClipPlanes::Frustum.excludes(&bounds.corners().map(|c| self.to_clip(c)))
+    }
+
+    pub fn to_clip(&self, p: Vec3) -> Vec4{
+        clipping::to_clip(&self.view_projection, p)
+    }
+    // screen position and 0..1 depth of a clip space point
+    pub fn clip_to_screen(&self, p_clip: Vec4) -> (egui::Pos2, f32){
+        clipping::clip_to_screen(self.rect, p_clip)
+    }
+    // screen position and depth of a point, None if it is behind the near plane or not a number
+    pub fn world_to_screen(&self, p: Vec3) -> Option<(egui::Pos2, f32)>{
+        clipping::world_to_screen(&self.view_projection, self.rect, p)
+    }
+    // screen size in points of a radius around p, measured along the camera x axis
+    pub fn screen_radius(&self, p: Vec3, radius: f32) -> f32{
+        clipping::screen_radius(&self.view_projection, self.rect, p, self.right, radius)
+    }
+    // 1 at the camera going down to 0 at the far plane, always 1 without distance fade
+    pub fn fade(&self, p: Vec3) -> f32{
+        if !self.style.distance_fade { return 1.0; }
+        (1.0 - (self.eye - p).length() / self.camera.get_far()).clamp(0.0, 1.0)
+    }
+    // color with its alpha scaled by `amount` and the distance fade at p
+    pub fn faded(&self, color: egui::Color32, p: Vec3, amount: f32) -> egui::Color32{
+        let alpha = (color.a() as f32 / 256.0) * amount * self.fade(p) * 255.0;
+        egui::Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), alpha as u8)
+    }
+
+    pub fn add(&mut self, depth: f32, shape: impl Into<egui::Shape>){
+        self.out.add(depth, shape);
+    }
+    // text centered on p
+    pub fn label(&mut self, p: Vec3, text: String, color: egui::Color32){
+        if let Some((pos, depth)) = self.world_to_screen(p) {
+            self.out.label(depth, pos, text, color);
+        }
+    }
+    // Points moved by xform into the space of the shape, with their clip space positions. Big shapes get
+    // transformed in parallel with the rayon feature
+    pub fn transform_points(&self, xform: Mat4, pts: &[Vec3]) -> Vec<(Vec3, Vec4)>{
+        let view_projection = self.view_projection;
+        parallel::map(pts, |p| {
+            let p = xform.transform_point3(*p);
+            (p, clipping::to_clip(&view_projection, p))
+        })
+    }
+    // Screen positions and depths of the points `point` gives for every item, None for points outside the clip
+    // planes. Big shapes get projected in parallel with the rayon feature
+    pub fn project_points<T: Sync>(&self, items: &[T], point: impl Fn(&T) -> Vec3 + Sync + Send) -> Vec<Option<(egui::Pos2, f32)>>{
+        let (view_projection, rect, clip_planes) = (self.view_projection, self.rect, self.style.clip_planes);
+        parallel::map(items, |item| project(&view_projection, rect, clip_planes, point(item)))
+    }
+
+    // filled circle with a fixed screen radius centered on p
+    pub fn circle(&mut self, p: Vec3, radius: f32, fill: egui::Color32, stroke: egui::Stroke){
+        let Some((center, depth)) = project(&self.view_projection, self.rect, self.style.clip_planes, p) else { return; };
+        self.out.add(depth, egui::epaint::CircleShape { center, radius, fill, stroke });
+    }
+    pub fn segment(&mut self, a: Vec3, b: Vec3, stroke: egui::Stroke){
+        let Some((clip_a, clip_b)) = clipping::clip_segment(self.to_clip(a), self.to_clip(b), self.style.clip_planes) else { return; };
+        let (screen_a, depth_a) = self.clip_to_screen(clip_a);
+        let (screen_b, depth_b) = self.clip_to_screen(clip_b);
+        self.out.add((depth_a + depth_b) / 2.0, egui::Shape::LineSegment { points: [screen_a, screen_b], stroke });
+    }
+    // open polyline, split into several paths where it leaves the clip volume
+    pub fn polyline(&mut self, pts: &[Vec3], stroke: egui::Stroke){
+        let clip_pts: Vec<Vec4> = pts.iter().map(|p| self.to_clip(*p)).collect();
+        for piece in clipping::clip_polyline(&clip_pts, self.style.clip_planes) {
+            let (path_pts, depth) = self.piece_to_screen(&piece);
+            self.out.add(depth, egui::epaint::PathShape::line(path_pts, stroke));
+        }
+    }
+    // A single face wound clockwise seen from the front, fan triangulated. `color` is the fill that gets
+    // shaded by the lights, with `normal` if the face has one to shade with and its flat normal otherwise,
+    // `stroke` the outline. Shaded faces that end up wound counter clockwise on screen are turned away
+    // from the camera and get culled, in wireframe their outline fades out instead
+    pub fn face(&mut self, pts: &[Vec3], normal: Option<Vec3>, color: egui::Color32, stroke: egui::Stroke, display: DisplayMode){
+        let points: Vec<(Vec3, Vec4)> = pts.iter().map(|p| (*p, self.to_clip(*p))).collect();
+        self.face_clipped(&points, normal, color, stroke, display);
+    }
+    // same as face, with the clip space position of every point already known, e.g. from transform_points
+    pub fn face_clipped(&mut self, points: &[(Vec3, Vec4)], normal: Option<Vec3>, color: egui::Color32, stroke: egui::Stroke, display: DisplayMode){
+        if points.len() < 3 { return; }
+        let (pts, clip_pts): (Vec<Vec3>, Vec<Vec4>) = points.iter().copied().unzip();
+        let shaded = display != DisplayMode::Wireframe;
+        let flat_normal = face_normal(&pts);
+        let center = pts.iter().sum::<Vec3>() / pts.len() as f32;
+
+        let mut face_depth = 0.0;
+        if shaded {
+            let clipped: Vec<(egui::Pos2, f32)> = clipping::clip_polygon(&clip_pts, self.style.clip_planes).into_iter()
+                .map(|p_clip| self.clip_to_screen(p_clip))
+                .collect();
+            // back-face culling on screen, that works the same for any projection
+            if signed_area(&clipped) <= 0.0 { return; }
+            // shaded in world space, so a scaled parent doesn't distort the light falloff or the angles
+            let world_center = self.parent_xform.transform_point3(center);
+            let world_normal = self.normal_xform.transform_vector3(normal.unwrap_or(flat_normal)).normalize_or_zero();
+            let fill = self.lighting.shade(color, world_center, world_normal, self.camera.to_viewer(world_center));
+            let mut mesh = egui::epaint::Mesh::default();
+            for (pos, depth) in clipped.iter() {
+                face_depth += depth / clipped.len() as f32;
+                mesh.colored_vertex(*pos, fill);
+            }
+            for i in 1..clipped.len() as u32 - 1 {
+                mesh.add_triangle(0, i, i + 1);
+            }
+            self.out.add(face_depth, mesh);
+            if display == DisplayMode::Shaded { return; }
+        }
+
+        let mut stroke = stroke;
+        if !shaded {
+            let n_dot_v = flat_normal.dot(self.to_viewer(center));
+            stroke.color = egui::Color32::from_rgba_unmultiplied(stroke.color.r(), stroke.color.g(), stroke.color.b(), (n_dot_v.max(0.0) * 255.0) as u8);
+        }
+        let mut closed_pts = clip_pts;
+        closed_pts.push(closed_pts[0]);
+        for piece in clipping::clip_polyline(&closed_pts, self.style.clip_planes) {
+            let (path_pts, mut depth) = self.piece_to_screen(&piece);
+            // on shaded faces the wire sorts right in front of its own face
+            if shaded { depth = f32::min(face_depth, depth) - f32::EPSILON; }
+            self.out.add(depth, egui::epaint::PathShape::line(path_pts, stroke));
+        }
+    }
+
+    // screen points and average depth of a clipped piece
+    fn piece_to_screen(&self, piece: &[Vec4]) -> (Vec<egui::Pos2>, f32){
+        let mut depth = 0.0;
+        let pts = piece.iter().map(|p_clip| {
+            let (pos, z) = self.clip_to_screen(*p_clip);
+            depth += z / piece.len() as f32;
+            pos
+        }).collect();
+        (pts, depth)
+    }
+}
+
+// twice the signed area of a screen space polygon, positive if it is wound clockwise on screen where y points down
+fn signed_area(pts: &[(egui::Pos2, f32)]) -> f32{
+    (0..pts.len()).map(|i| {
+        let (a, b) = (pts[i].0, pts[(i + 1) % pts.len()].0);
+        a.x * b.y - b.x * a.y
+    }).sum()
+}
+
+// screen position and depth of p if it is inside the clip planes
+fn project(view_projection: &Mat4, rect: egui::Rect, clip_planes: ClipPlanes, p: Vec3) -> Option<(egui::Pos2, f32)>{
+    let p_clip = clipping::to_clip(view_projection, p);
+    clip_planes.contains(p_clip).then(|| clipping::clip_to_screen(rect, p_clip))
+}
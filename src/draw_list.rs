@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet};
+use egui;
+
+// order in which the collected primitives get painted
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DepthSort {
+    None, // insertion order, shapes are painted in the order they were added to the scene
+    PerObject, // back to front by the average depth of each shape, primitives of a shape keep their order
+    PerPrimitive, // back to front by the depth of every single line, circle and path
+}
+
+pub enum Primitive {
+    Shape(egui::Shape),
+    Label { pos: egui::Pos2, text: String, color: egui::Color32 }, // laid out when painted, so no fonts are needed while drawing
+}
+
+pub struct DrawItem {
+    pub depth: f32, // projected depth, bigger is further away from the camera
+    pub object: usize, // index of the shape that added the primitive
+    pub primitive: Primitive,
+}
+
+// collects everything the shapes draw during a frame so it can be depth sorted before painting
+pub struct DrawList {
+    pub items: Vec<DrawItem>,
+    object: usize,
+}
+impl DrawList {
+    pub fn new()->Self{
+        DrawList {
+            items: Vec::new(),
+            object: 0,
+        }
+    }
+    // everything added from now on belongs to the shape with this index
+    pub fn begin_object(&mut self, object: usize){
+        self.object = object;
+    }
+    pub fn add(&mut self, depth: f32, shape: impl Into<egui::Shape>){
+        self.items.push(DrawItem {
+            depth,
+            object: self.object,
+            primitive: Primitive::Shape(shape.into()),
+        });
+    }
+    pub fn label(&mut self, depth: f32, pos: egui::Pos2, text: String, color: egui::Color32){
+        self.items.push(DrawItem {
+            depth,
+            object: self.object,
+            primitive: Primitive::Label { pos, text, color },
+        });
+    }
+
+    pub fn sort(&mut self, mode: DepthSort){
+        match mode {
+            DepthSort::None => {},
+            DepthSort::PerObject => {
+                let n_objects = self.items.iter().map(|i| i.object + 1).max().unwrap_or(0);
+                let mut sums = vec![(0.0_f32, 0_usize); n_objects];
+                for item in self.items.iter().filter(|i| i.depth.is_finite()) {
+                    sums[item.object].0 += item.depth;
+                    sums[item.object].1 += 1;
+                }
+                let depths: Vec<f32> = sums.iter().map(|(sum, n)| if *n > 0 { sum / *n as f32 } else { 0.0 }).collect();
+                self.items.sort_by(|a, b| depths[b.object].total_cmp(&depths[a.object])); // stable, keeps the order inside an object
+            },
+            DepthSort::PerPrimitive => {
+                self.items.sort_by(|a, b| b.depth.total_cmp(&a.depth));
+            },
+        }
+    }
+
+    // screen space bounds of everything each object drew, labels are left out
+    pub fn object_bounds(&self) -> HashMap<usize, egui::Rect>{
+        let mut bounds: HashMap<usize, egui::Rect> = HashMap::new();
+        for item in self.items.iter() {
+            let Primitive::Shape(shape) = &item.primitive else { continue; };
+            let rect = shape.visual_bounding_rect();
+            if !rect.is_finite() { continue; }
+            bounds.entry(item.object).and_modify(|r| *r = r.union(rect)).or_insert(rect);
+        }
+        bounds
+    }
+
+    // recolors the primitives of the given objects with the highlight color and widens their strokes,
+    // the alpha of each primitive is kept so distance and facing attenuation still show
+    pub fn highlight(&mut self, objects: &HashSet<usize>, highlight: egui::Stroke){
+        let recolor = |c: egui::Color32| -> egui::Color32 {
+            if c == egui::Color32::TRANSPARENT { return c; }
+            egui::Color32::from_rgba_unmultiplied(highlight.color.r(), highlight.color.g(), highlight.color.b(), c.a())
+        };
+        let restroke = |s: &mut egui::Stroke| {
+            s.color = recolor(s.color);
+            s.width += highlight.width;
+        };
+        for item in self.items.iter_mut().filter(|i| objects.contains(&i.object)) {
+            match &mut item.primitive {
+                Primitive::Shape(egui::Shape::LineSegment { stroke, .. }) => restroke(stroke),
+                Primitive::Shape(egui::Shape::Circle(circle)) => {
+                    circle.fill = recolor(circle.fill);
+                    restroke(&mut circle.stroke);
+                },
+                Primitive::Shape(egui::Shape::Path(path)) => {
+                    path.fill = recolor(path.fill);
+                    restroke(&mut path.stroke);
+                },
+                Primitive::Shape(egui::Shape::Mesh(mesh)) => {
+                    for v in mesh.vertices.iter_mut() { v.color = recolor(v.color); }
+                },
+                Primitive::Shape(_) => {},
+                Primitive::Label { color, .. } => *color = recolor(*color),
+            }
+        }
+    }
+
+    // Merges runs of lines, circles, outlines and meshes into single meshes, keeping the paint order,
+    // so egui tessellates a few big meshes instead of a shape per line. Lines become thin quads without
+    // anti-aliasing, labels and filled paths end a run. Call it last, after highlighting and sorting
+    pub fn batch(&mut self){
+        let mut items = Vec::with_capacity(self.items.len());
+        let mut mesh = egui::Mesh::default();
+        let mut first: Option<(f32, usize)> = None; // depth and object of the first primitive in the mesh
+        let flush = |items: &mut Vec<DrawItem>, mesh: &mut egui::Mesh, first: Option<(f32, usize)>| {
+            if let Some((depth, object)) = first {
+                items.push(DrawItem { depth, object, primitive: Primitive::Shape(std::mem::take(mesh).into()) });
+            }
+        };
+        for DrawItem { depth, object, primitive } in std::mem::take(&mut self.items) {
+            match primitive {
+                Primitive::Shape(egui::Shape::LineSegment { points, stroke }) => add_line(&mut mesh, points[0], points[1], stroke),
+                Primitive::Shape(egui::Shape::Circle(circle)) => add_circle(&mut mesh, &circle),
+                Primitive::Shape(egui::Shape::Path(path)) if path.fill == egui::Color32::TRANSPARENT => {
+                    for pair in path.points.windows(2) {
+                        add_line(&mut mesh, pair[0], pair[1], path.stroke);
+                    }
+                    if path.closed && path.points.len() > 2 {
+                        add_line(&mut mesh, path.points[path.points.len() - 1], path.points[0], path.stroke);
+                    }
+                },
+                Primitive::Shape(egui::Shape::Mesh(other)) if mesh.is_empty() || mesh.texture_id == other.texture_id => mesh.append(other),
+                primitive => {
+                    flush(&mut items, &mut mesh, first.take());
+                    items.push(DrawItem { depth, object, primitive });
+                    continue;
+                },
+            }
+            first.get_or_insert((depth, object));
+        }
+        flush(&mut items, &mut mesh, first);
+        self.items = items;
+    }
+
+    pub fn has_labels(&self) -> bool{
+        self.items.iter().any(|item| matches!(item.primitive, Primitive::Label { .. }))
+    }
+    // the primitives as plain epaint shapes, labels get laid out with the given fonts and are left out without any
+    pub fn into_shapes(self, fonts: Option<&egui::epaint::Fonts>) -> Vec<egui::Shape>{
+        self.items.into_iter().filter_map(|item| match item.primitive {
+            Primitive::Shape(shape) => Some(shape),
+            Primitive::Label { pos, text, color } => fonts.map(|fonts| {
+                egui::Shape::text(fonts, pos, egui::Align2::CENTER_CENTER, text, egui::FontId::monospace(16.0), color)
+            }),
+        }).collect()
+    }
+
+    pub fn paint(self, painter: &egui::Painter){
+        painter.extend(painter.ctx().fonts(|fonts| self.into_shapes(Some(fonts))));
+    }
+}
+
+impl Default for DrawList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// quad along the line, lines thinner than a point get drawn a point wide and fainter instead
+fn add_line(mesh: &mut egui::Mesh, a: egui::Pos2, b: egui::Pos2, stroke: egui::Stroke){
+    if stroke.width <= 0.0 || stroke.color == egui::Color32::TRANSPARENT { return; }
+    let length = (b - a).length();
+    if length == 0.0 || !length.is_finite() { return; }
+    let dir = (b - a) / length;
+    let (width, color) = if stroke.width < 1.0 { (1.0, stroke.color.gamma_multiply(stroke.width)) } else { (stroke.width, stroke.color) };
+    let n = dir.rot90() * width / 2.0;
+    let idx = mesh.vertices.len() as u32;
+    mesh.colored_vertex(a + n, color);
+    mesh.colored_vertex(a - n, color);
+    mesh.colored_vertex(b + n, color);
+    mesh.colored_vertex(b - n, color);
+    mesh.add_triangle(idx, idx + 1, idx + 2);
+    mesh.add_triangle(idx + 1, idx + 2, idx + 3);
+}
+
+// filled disc as a triangle fan, with more segments the bigger it is, plus its outline
+fn add_circle(mesh: &mut egui::Mesh, circle: &egui::epaint::CircleShape){
+    if circle.radius <= 0.0 { return; }
+    let segments = ((circle.radius * 2.0).ceil() as usize).clamp(6, 32);
+    let ring = |i: usize| {
+        let a = (i % segments) as f32 / segments as f32 * std::f32::consts::TAU;
+        circle.center + circle.radius * egui::Vec2::angled(a)
+    };
+    if circle.fill != egui::Color32::TRANSPARENT {
+        let idx = mesh.vertices.len() as u32;
+        mesh.colored_vertex(circle.center, circle.fill);
+        for i in 0..segments {
+            mesh.colored_vertex(ring(i), circle.fill);
+        }
+        for i in 0..segments as u32 {
+            mesh.add_triangle(idx, idx + 1 + i, idx + 1 + (i + 1) % segments as u32);
+        }
+    }
+    if circle.stroke.width > 0.0 {
+        for i in 0..segments {
+            add_line(mesh, ring(i), ring(i + 1), circle.stroke);
+        }
+    }
+}
@@ -1,6 +1,22 @@
 pub mod widgets;
 pub mod shapes;
 pub mod camera;
+pub mod navigation;
+pub mod draw_list;
+pub mod draw_context;
+pub mod clipping;
+pub mod bounds;
+pub mod octree;
+pub mod bvh;
+mod cache;
+mod parallel;
+pub mod picking;
+pub mod scene;
+pub mod selection;
+pub mod lighting;
+pub mod loaders;
+pub mod colormap;
+pub mod render;
 
 pub use widgets::{*};
 pub use shapes::{*};
\ No newline at end of file
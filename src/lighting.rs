@@ -0,0 +1,140 @@
+use glam::{Vec3, Mat4};
+
+// how the intensity of a light decays with the distance to the lit point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Falloff {
+    None, // same intensity at any distance
+    Linear, // intensity / distance
+    Quadratic, // intensity / distance^2, physically based
+}
+
+// what kind of emitter a light is, they all shine along Light::direction except the point light
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LightKind {
+    Point, // shines in every direction from its position
+    Directional, // parallel light coming from infinitely far away, position and falloff are ignored
+    Spot { inner_angle: f32, outer_angle: f32 }, // cone half angles in degrees, fades out from the inner to the outer one
+    Area { area: f32 }, // one sided surface around its position, lit points see it at an angle and never closer than its size
+}
+
+// a light contributing to the shading of filled faces
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Light {
+    pub position: Vec3,
+    pub direction: Vec3, // normalized direction the light travels in
+    pub color: egui::Color32,
+    pub intensity: f32,
+    pub falloff: Falloff,
+    pub kind: LightKind,
+}
+impl Light {
+    pub fn new(position: Vec3, color: egui::Color32, intensity: f32, falloff: Falloff)->Self{
+        Light {
+            position,
+            direction: -Vec3::Z,
+            color,
+            intensity,
+            falloff,
+            kind: LightKind::Point,
+        }
+    }
+    pub fn directional(direction: Vec3, color: egui::Color32, intensity: f32)->Self{
+        Light {
+            direction: direction.normalize_or_zero(),
+            kind: LightKind::Directional,
+            ..Light::new(Vec3::ZERO, color, intensity, Falloff::None)
+        }
+    }
+    pub fn spot(position: Vec3, direction: Vec3, color: egui::Color32, intensity: f32, falloff: Falloff, inner_angle: f32, outer_angle: f32)->Self{
+        Light {
+            direction: direction.normalize_or_zero(),
+            kind: LightKind::Spot { inner_angle, outer_angle },
+            ..Light::new(position, color, intensity, falloff)
+        }
+    }
+    pub fn area(position: Vec3, direction: Vec3, area: f32, color: egui::Color32, intensity: f32, falloff: Falloff)->Self{
+        Light {
+            direction: direction.normalize_or_zero(),
+            kind: LightKind::Area { area },
+            ..Light::new(position, color, intensity, falloff)
+        }
+    }
+    pub fn transformed(&self, xform: Mat4) -> Light{
+        Light {
+            position: xform.transform_point3(self.position),
+            direction: xform.transform_vector3(self.direction).normalize_or_zero(),
+            ..*self
+        }
+    }
+    // normalized direction from p towards the light, the direction the light comes in from
+    fn incoming(&self, p: Vec3) -> Vec3{
+        match self.kind {
+            LightKind::Directional => -self.direction,
+            _ => (self.position - p).normalize_or_zero(),
+        }
+    }
+    // color and intensity of the light arriving at p, before the angle with the surface is accounted for
+    fn radiance(&self, p: Vec3) -> Vec3{
+        let mut dist = (self.position - p).length().max(1e-4);
+        let to_p = -self.incoming(p);
+        let emission = match self.kind {
+            LightKind::Point => 1.0,
+            LightKind::Directional => return self.linear_color() * self.intensity,
+            LightKind::Spot { inner_angle, outer_angle } => {
+                let cos_inner = inner_angle.min(outer_angle).to_radians().cos();
+                let cos_outer = outer_angle.to_radians().cos();
+                let cos_angle = to_p.dot(self.direction);
+                if cos_inner - cos_outer > f32::EPSILON { ((cos_angle - cos_outer) / (cos_inner - cos_outer)).clamp(0.0, 1.0) }
+                else if cos_angle >= cos_outer { 1.0 } else { 0.0 } // hard edged cone
+            },
+            LightKind::Area { area } => {
+                dist = dist.max(area.max(0.0).sqrt() * 0.5);
+                to_p.dot(self.direction).max(0.0)
+            },
+        };
+        let attenuation = match self.falloff {
+            Falloff::None => 1.0,
+            Falloff::Linear => 1.0 / dist,
+            Falloff::Quadratic => 1.0 / (dist * dist),
+        };
+        self.linear_color() * self.intensity * emission * attenuation
+    }
+    fn linear_color(&self) -> Vec3{
+        Vec3::new(self.color.r() as f32, self.color.g() as f32, self.color.b() as f32) / 255.0
+    }
+}
+
+// the lights of a scene, collected by the viewport before the shapes get drawn
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lighting {
+    pub lights: Vec<Light>,
+    pub ambient: f32, // keeps faces facing away from every light from going completely black
+}
+impl Lighting {
+    pub fn new()->Self{
+        Lighting {
+            lights: Vec::new(),
+            ambient: 0.15,
+        }
+    }
+    // Lambert shading of a surface color at p with normal n, all in world space. Without any lights in the scene
+    // a white headlight shines along the line of sight, `to_viewer` is the direction from p back to the viewer
+    pub fn shade(&self, color: egui::Color32, p: Vec3, n: Vec3, to_viewer: Vec3) -> egui::Color32{
+        let headlight = [Light::directional(-to_viewer, egui::Color32::WHITE, 1.0)];
+        let lights = if self.lights.is_empty() { &headlight[..] } else { &self.lights[..] };
+        let mut light = Vec3::splat(self.ambient);
+        for l in lights.iter() {
+            let n_dot_l = n.dot(l.incoming(p)).max(0.0);
+            light += l.radiance(p) * n_dot_l * (1.0 - self.ambient);
+        }
+        // scaling the premultiplied channels keeps the alpha as it is
+        let scale = |c: u8, k: f32| (c as f32 * k.clamp(0.0, 1.0)).round() as u8;
+        egui::Color32::from_rgba_premultiplied(scale(color.r(), light.x), scale(color.g(), light.y), scale(color.b(), light.z), color.a())
+    }
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::io::BufRead;
+use std::path::Path;
+
+use glam::{Vec3, Mat4};
+
+use crate::shapes::polymesh::Polymesh;
+
+#[derive(Debug)]
+pub enum ObjError {
+    Io(std::io::Error),
+    MalformedLine { line: usize, content: String }, // a v, vn or f line that could not be parsed
+    IndexOutOfRange { line: usize, index: i64 }, // a face referencing a vertex or normal that doesn't exist
+}
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "could not read obj: {}", e),
+            ObjError::MalformedLine { line, content } => write!(f, "malformed obj line {}: {:?}", line, content),
+            ObjError::IndexOutOfRange { line, index } => write!(f, "index {} out of range on obj line {}", index, line),
+        }
+    }
+}
+impl std::error::Error for ObjError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ObjError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for ObjError {
+    fn from(e: std::io::Error) -> Self {
+        ObjError::Io(e)
+    }
+}
+
+// faces of one object or group while reading, indices still point into the positions of the whole file
+#[derive(Default)]
+struct MeshBuilder {
+    name: Option<String>,
+    indices: Vec<usize>,
+    normal_indices: Vec<Option<usize>>,
+    counts: Vec<usize>,
+}
+impl MeshBuilder {
+    fn build(self, positions: &[Vec3], normals: &[Vec3]) -> Polymesh {
+        // only keep the positions this mesh uses, in the order they are first referenced
+        let mut remap = vec![usize::MAX; positions.len()];
+        let mut points = Vec::new();
+        let indices = self.indices.iter().map(|i| {
+            if remap[*i] == usize::MAX {
+                remap[*i] = points.len();
+                points.push(positions[*i]);
+            }
+            remap[*i]
+        }).collect();
+        let mut mesh = Polymesh::new(self.name, Mat4::IDENTITY, 1.0, egui::Color32::LIGHT_GRAY, points, indices, self.counts);
+        if self.normal_indices.iter().all(|n| n.is_some()) {
+            mesh.normals = self.normal_indices.iter().map(|n| normals[n.unwrap()]).collect();
+        }
+        mesh
+    }
+}
+
+// Reads a Wavefront OBJ, every object or group with faces becomes its own polymesh.
+// Faces get flipped from the counter clockwise winding of OBJ to the clockwise winding polymesh expects
+pub fn read_obj(reader: impl BufRead) -> Result<Vec<Polymesh>, ObjError> {
+    let mut positions: Vec<Vec3> = Vec::new();
+    let mut normals: Vec<Vec3> = Vec::new();
+    let mut meshes: Vec<MeshBuilder> = Vec::new();
+    let mut current = MeshBuilder::default();
+
+    for (line_i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_nr = line_i + 1;
+        let malformed = || ObjError::MalformedLine { line: line_nr, content: line.clone() };
+        let content = line.split('#').next().unwrap_or("");
+        let mut tokens = content.split_whitespace();
+        let Some(keyword) = tokens.next() else { continue; };
+        match keyword {
+            "v" | "vn" => {
+                let xyz: Vec<f32> = tokens.take(3).map(|t| t.parse::<f32>()).collect::<Result<_, _>>().map_err(|_| malformed())?;
+                if xyz.len() != 3 { return Err(malformed()); }
+                let v = Vec3::new(xyz[0], xyz[1], xyz[2]);
+                if keyword == "v" { positions.push(v); } else { normals.push(v); }
+            },
+            "f" => {
+                let mut face: Vec<(usize, Option<usize>)> = Vec::new();
+                for token in tokens {
+                    // v, v/vt, v//vn or v/vt/vn, indices start at 1 and negative ones count back from the end
+                    let mut parts = token.split('/');
+                    let resolve = |part: Option<&str>, len: usize| -> Result<Option<usize>, ObjError> {
+                        let Some(part) = part.filter(|p| !p.is_empty()) else { return Ok(None); };
+                        let index: i64 = part.parse().map_err(|_| malformed())?;
+                        let resolved = if index < 0 { len as i64 + index } else { index - 1 };
+                        if index == 0 || resolved < 0 || resolved >= len as i64 {
+                            return Err(ObjError::IndexOutOfRange { line: line_nr, index });
+                        }
+                        Ok(Some(resolved as usize))
+                    };
+                    let vertex = resolve(parts.next(), positions.len())?.ok_or_else(malformed)?;
+                    let _texcoord = parts.next();
+                    let normal = resolve(parts.next(), normals.len())?;
+                    face.push((vertex, normal));
+                }
+                if face.len() < 3 { return Err(malformed()); }
+                face.reverse();
+                current.counts.push(face.len());
+                for (vertex, normal) in face {
+                    current.indices.push(vertex);
+                    current.normal_indices.push(normal);
+                }
+            },
+            "o" | "g" => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+                let next = MeshBuilder { name: (!name.is_empty()).then_some(name), ..Default::default() };
+                let finished = std::mem::replace(&mut current, next);
+                if !finished.counts.is_empty() { meshes.push(finished); }
+            },
+            _ => {}, // texture coordinates, materials, smoothing groups, ...
+        }
+    }
+    if !current.counts.is_empty() { meshes.push(current); }
+
+    Ok(meshes.into_iter().map(|m| m.build(&positions, &normals)).collect())
+}
+
+pub fn load_obj(path: impl AsRef<Path>) -> Result<Vec<Polymesh>, ObjError> {
+    let file = std::fs::File::open(path)?;
+    read_obj(std::io::BufReader::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIANGLE: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\n";
+
+    #[test]
+    fn negative_indices_count_back_from_the_last_vertex() {
+        let meshes = read_obj(format!("{}f -3 -2 -1\n", TRIANGLE).as_bytes()).unwrap();
+        assert_eq!(meshes.len(), 1);
+        let mesh = &meshes[0];
+        assert_eq!(mesh.counts(), vec![3]);
+        // flipped to clockwise, the points in the order the face uses them
+        assert_eq!(mesh.indices(), vec![0, 1, 2]);
+        assert_eq!(mesh.points(), vec![Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::ZERO]);
+    }
+
+    #[test]
+    fn faces_with_normals_keep_a_normal_per_face_vertex() {
+        let obj = format!("{}vn 0 0 1\nvn 0 0 -1\nf 1//1 2//1 3//2\n", TRIANGLE);
+        let mesh = read_obj(obj.as_bytes()).unwrap().remove(0);
+        assert_eq!(mesh.normals, vec![-Vec3::Z, Vec3::Z, Vec3::Z]);
+
+        // a face without normals leaves the whole mesh without them
+        let mesh = read_obj(format!("{}vn 0 0 1\nf 1//1 2//1 3//1\nf 1 2 3\n", TRIANGLE).as_bytes()).unwrap().remove(0);
+        assert!(mesh.normals.is_empty());
+    }
+
+    #[test]
+    fn objects_and_groups_become_separate_meshes() {
+        let obj = format!("{}v 1 1 0\no first\nf 1 2 3\ng second part\nf 1 3 4\nf 2 4 3\ng empty\n", TRIANGLE);
+        let meshes = read_obj(obj.as_bytes()).unwrap();
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(meshes[0].name.as_deref(), Some("first"));
+        assert_eq!(meshes[0].counts(), vec![3]);
+        assert_eq!(meshes[1].name.as_deref(), Some("second part"));
+        assert_eq!(meshes[1].counts(), vec![3, 3]);
+        assert_eq!(meshes[1].points().len(), 4);
+    }
+
+    #[test]
+    fn malformed_lines_are_reported_with_their_line_number() {
+        match read_obj("v 0 0 0\nv 1 0\n".as_bytes()) {
+            Err(ObjError::MalformedLine { line: 2, content }) => assert_eq!(content, "v 1 0"),
+            other => panic!("{:?}", other.map(|m| m.len())),
+        }
+        assert!(matches!(read_obj(format!("{}f 1 2\n", TRIANGLE).as_bytes()), Err(ObjError::MalformedLine { line: 4, .. })));
+        assert!(matches!(read_obj(format!("{}f 1 x 3\n", TRIANGLE).as_bytes()), Err(ObjError::MalformedLine { line: 4, .. })));
+    }
+
+    #[test]
+    fn indices_outside_the_vertices_are_out_of_range() {
+        assert!(matches!(read_obj(format!("{}f 1 2 4\n", TRIANGLE).as_bytes()), Err(ObjError::IndexOutOfRange { line: 4, index: 4 })));
+        assert!(matches!(read_obj(format!("{}f 0 1 2\n", TRIANGLE).as_bytes()), Err(ObjError::IndexOutOfRange { line: 4, index: 0 })));
+        assert!(matches!(read_obj(format!("{}f -4 1 2\n", TRIANGLE).as_bytes()), Err(ObjError::IndexOutOfRange { line: 4, index: -4 })));
+        assert!(matches!(read_obj(format!("{}f 1//1 2//1 3//1\n", TRIANGLE).as_bytes()), Err(ObjError::IndexOutOfRange { line: 4, index: 1 })));
+    }
+}
@@ -0,0 +1,438 @@
+use std::fmt;
+use std::io::{BufRead, Read};
+use std::path::Path;
+
+use glam::{Vec3, Mat4};
+
+use crate::shapes::{Shape3d, DisplayMode};
+use crate::shapes::point_cloud::PointCloud;
+use crate::shapes::polymesh::Polymesh;
+
+#[derive(Debug)]
+pub enum PlyError {
+    Io(std::io::Error),
+    BadHeader(String), // not a ply file or a header line that could not be parsed
+    UnsupportedFormat(String), // only ascii and binary_little_endian are read
+    UnsupportedPropertyType { element: String, property: String, type_name: String },
+    MissingProperty { element: String, property: String }, // e.g. a vertex element without x
+    InvalidValue(String), // a value in an ascii body that could not be parsed, or a face index that is not a whole number
+    UnexpectedEof, // the body holds less data than the header announced
+    IndexOutOfRange { face: usize, index: i64 },
+}
+impl fmt::Display for PlyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlyError::Io(e) => write!(f, "could not read ply: {}", e),
+            PlyError::BadHeader(line) => write!(f, "bad ply header line: {:?}", line),
+            PlyError::UnsupportedFormat(format) => write!(f, "unsupported ply format {:?}, only ascii and binary_little_endian are supported", format),
+            PlyError::UnsupportedPropertyType { element, property, type_name } => write!(f, "unsupported type {:?} of ply property {}.{}", type_name, element, property),
+            PlyError::MissingProperty { element, property } => write!(f, "ply element {} has no property {}", element, property),
+            PlyError::InvalidValue(value) => write!(f, "invalid ply value {:?}", value),
+            PlyError::UnexpectedEof => write!(f, "ply file ended before all elements were read"),
+            PlyError::IndexOutOfRange { face, index } => write!(f, "vertex index {} out of range in ply face {}", index, face),
+        }
+    }
+}
+impl std::error::Error for PlyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PlyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for PlyError {
+    fn from(e: std::io::Error) -> Self {
+        match e.kind() {
+            std::io::ErrorKind::UnexpectedEof => PlyError::UnexpectedEof,
+            _ => PlyError::Io(e),
+        }
+    }
+}
+
+// what a ply file turns into, depending on whether it has faces
+pub enum PlyShape {
+    PointCloud(PointCloud),
+    Polymesh(Polymesh),
+}
+impl From<PlyShape> for Box<dyn Shape3d> {
+    fn from(shape: PlyShape) -> Self {
+        match shape {
+            PlyShape::PointCloud(p) => Box::new(p),
+            PlyShape::Polymesh(p) => Box::new(p),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ScalarType { I8, U8, I16, U16, I32, U32, F32, F64 }
+impl ScalarType {
+    fn parse(name: &str) -> Option<ScalarType> {
+        Some(match name {
+            "char" | "int8" => ScalarType::I8,
+            "uchar" | "uint8" => ScalarType::U8,
+            "short" | "int16" => ScalarType::I16,
+            "ushort" | "uint16" => ScalarType::U16,
+            "int" | "int32" => ScalarType::I32,
+            "uint" | "uint32" => ScalarType::U32,
+            "float" | "float32" => ScalarType::F32,
+            "double" | "float64" => ScalarType::F64,
+            _ => return None,
+        })
+    }
+    fn is_float(&self) -> bool {
+        matches!(self, ScalarType::F32 | ScalarType::F64)
+    }
+    fn size(&self) -> usize {
+        match self {
+            ScalarType::I8 | ScalarType::U8 => 1,
+            ScalarType::I16 | ScalarType::U16 => 2,
+            ScalarType::I32 | ScalarType::U32 | ScalarType::F32 => 4,
+            ScalarType::F64 => 8,
+        }
+    }
+}
+
+enum PropertyKind {
+    Scalar(ScalarType),
+    List { count: ScalarType, item: ScalarType },
+}
+struct Property {
+    name: String,
+    kind: PropertyKind,
+}
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+impl Element {
+    fn find(&self, name: &str) -> Option<usize> {
+        self.properties.iter().position(|p| p.name == name)
+    }
+}
+
+// values of the body, either whitespace separated text or packed little endian binary
+enum Body<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary(&'a [u8]),
+}
+impl Body<'_> {
+    fn read(&mut self, ty: ScalarType) -> Result<f64, PlyError> {
+        match self {
+            Body::Ascii(tokens) => {
+                let token = tokens.next().ok_or(PlyError::UnexpectedEof)?;
+                token.parse::<f64>().map_err(|_| PlyError::InvalidValue(token.to_string()))
+            },
+            Body::Binary(data) => {
+                macro_rules! take {
+                    ($t:ty) => {{
+                        let mut bytes = [0u8; std::mem::size_of::<$t>()];
+                        data.read_exact(&mut bytes)?;
+                        <$t>::from_le_bytes(bytes) as f64
+                    }};
+                }
+                Ok(match ty {
+                    ScalarType::I8 => take!(i8),
+                    ScalarType::U8 => take!(u8),
+                    ScalarType::I16 => take!(i16),
+                    ScalarType::U16 => take!(u16),
+                    ScalarType::I32 => take!(i32),
+                    ScalarType::U32 => take!(u32),
+                    ScalarType::F32 => take!(f32),
+                    ScalarType::F64 => take!(f64),
+                })
+            },
+        }
+    }
+    // false if there is not enough binary data left for n values, ascii bodies just run out of tokens
+    fn has_room(&self, n: usize, ty: ScalarType) -> bool {
+        match self {
+            Body::Ascii(_) => true,
+            Body::Binary(data) => n.checked_mul(ty.size()).is_some_and(|len| len <= data.len()),
+        }
+    }
+}
+
+fn read_header(reader: &mut impl BufRead) -> Result<(bool, Vec<Element>), PlyError> {
+    let next_line = |reader: &mut dyn BufRead| -> Result<String, PlyError> {
+        let mut buf = Vec::new();
+        if reader.read_until(b'\n', &mut buf)? == 0 { return Err(PlyError::UnexpectedEof); }
+        Ok(String::from_utf8_lossy(&buf).trim().to_string())
+    };
+    let magic = next_line(reader)?;
+    if magic != "ply" { return Err(PlyError::BadHeader(magic)); }
+
+    let mut binary = None;
+    let mut elements: Vec<Element> = Vec::new();
+    loop {
+        let line = next_line(reader)?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.as_slice() {
+            ["end_header"] => break,
+            ["format", "ascii", _] => binary = Some(false),
+            ["format", "binary_little_endian", _] => binary = Some(true),
+            ["format", format, ..] => return Err(PlyError::UnsupportedFormat(format.to_string())),
+            ["comment", ..] | ["obj_info", ..] | [] => {},
+            ["element", name, count] => {
+                let count = count.parse().map_err(|_| PlyError::BadHeader(line.clone()))?;
+                elements.push(Element { name: name.to_string(), count, properties: Vec::new() });
+            },
+            ["property", rest @ ..] => {
+                let element = elements.last_mut().ok_or_else(|| PlyError::BadHeader(line.clone()))?;
+                let element_name = element.name.clone();
+                let scalar = |type_name: &str, property: &str| ScalarType::parse(type_name).ok_or_else(|| PlyError::UnsupportedPropertyType {
+                    element: element_name.clone(),
+                    property: property.to_string(),
+                    type_name: type_name.to_string(),
+                });
+                let property = match rest {
+                    ["list", count, item, name] => Property {
+                        name: name.to_string(),
+                        kind: PropertyKind::List { count: scalar(count, name)?, item: scalar(item, name)? },
+                    },
+                    [type_name, name] => Property {
+                        name: name.to_string(),
+                        kind: PropertyKind::Scalar(scalar(type_name, name)?),
+                    },
+                    _ => return Err(PlyError::BadHeader(line.clone())),
+                };
+                element.properties.push(property);
+            },
+            _ => return Err(PlyError::BadHeader(line.clone())),
+        }
+    }
+    let binary = binary.ok_or_else(|| PlyError::BadHeader("missing format line".to_string()))?;
+    Ok((binary, elements))
+}
+
+// Reads an ascii or binary little endian ply. Files with faces become a polymesh, others a point cloud.
+// Per vertex or per face red / green / blue (and alpha) properties become the point or face colors,
+// meshes with colors get shaded so they show
+pub fn read_ply(mut reader: impl BufRead) -> Result<PlyShape, PlyError> {
+    let (binary, elements) = read_header(&mut reader)?;
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    let text;
+    let mut body = if binary {
+        Body::Binary(&data)
+    } else {
+        text = String::from_utf8_lossy(&data);
+        Body::Ascii(text.split_ascii_whitespace())
+    };
+
+    let mut points: Vec<Vec3> = Vec::new();
+    let mut colors: Vec<egui::Color32> = Vec::new();
+    let mut face_colors: Vec<egui::Color32> = Vec::new();
+    let mut indices: Vec<usize> = Vec::new();
+    let mut counts: Vec<usize> = Vec::new();
+    let mut has_faces = false;
+
+    for element in elements.iter() {
+        let missing = |property: &str| PlyError::MissingProperty { element: element.name.clone(), property: property.to_string() };
+        // positions of the properties we care about, everything else is read and dropped
+        let (xyz, face_indices) = match element.name.as_str() {
+            "vertex" => {
+                let xyz = [element.find("x").ok_or_else(|| missing("x"))?, element.find("y").ok_or_else(|| missing("y"))?, element.find("z").ok_or_else(|| missing("z"))?];
+                (Some(xyz), None)
+            },
+            "face" => {
+                has_faces |= element.count > 0;
+                let list = element.find("vertex_indices").or_else(|| element.find("vertex_index")).ok_or_else(|| missing("vertex_indices"))?;
+                (None, Some(list))
+            },
+            _ => (None, None),
+        };
+        let rgba = match [element.find("red"), element.find("green"), element.find("blue")] {
+            [Some(r), Some(g), Some(b)] if xyz.is_some() || face_indices.is_some() => Some([Some(r), Some(g), Some(b), element.find("alpha")]),
+            _ => None,
+        };
+
+        let mut values = vec![0.0_f64; element.properties.len()];
+        let mut value_types = vec![ScalarType::F32; element.properties.len()];
+        for _ in 0..element.count {
+            for (prop_i, property) in element.properties.iter().enumerate() {
+                match property.kind {
+                    PropertyKind::Scalar(ty) => {
+                        values[prop_i] = body.read(ty)?;
+                        value_types[prop_i] = ty;
+                    },
+                    PropertyKind::List { count, item } => {
+                        // the count comes straight from the file, a list longer than the data left is an error
+                        let n = body.read(count)? as usize;
+                        if !body.has_room(n, item) { return Err(PlyError::UnexpectedEof); }
+                        if face_indices != Some(prop_i) {
+                            for _ in 0..n { body.read(item)?; }
+                            continue;
+                        }
+                        let face_start = indices.len();
+                        for _ in 0..n {
+                            let index = body.read(item)?;
+                            if index.fract() != 0.0 { return Err(PlyError::InvalidValue(index.to_string())); }
+                            if index < 0.0 { return Err(PlyError::IndexOutOfRange { face: counts.len(), index: index as i64 }); }
+                            indices.push(index as usize);
+                        }
+                        // counter clockwise like in obj, polymesh expects clockwise faces
+                        indices[face_start..].reverse();
+                        counts.push(n);
+                    },
+                }
+            }
+            if let Some([x, y, z]) = xyz {
+                points.push(Vec3::new(values[x] as f32, values[y] as f32, values[z] as f32));
+            }
+            if let Some(channels) = rgba {
+                // integer channels are 0..255, float ones 0..1
+                let channel = |i: Option<usize>| -> u8 {
+                    let Some(i) = i else { return 255; };
+                    let v = if value_types[i].is_float() { values[i] * 255.0 } else { values[i] };
+                    v.round().clamp(0.0, 255.0) as u8
+                };
+                let color = egui::Color32::from_rgba_unmultiplied(channel(channels[0]), channel(channels[1]), channel(channels[2]), channel(channels[3]));
+                if xyz.is_some() { colors.push(color); } else { face_colors.push(color); }
+            }
+        }
+    }
+
+    let mut current_index = 0;
+    for (face, count) in counts.iter().enumerate() {
+        if let Some(index) = indices[current_index..current_index+count].iter().find(|i| **i >= points.len()) {
+            return Err(PlyError::IndexOutOfRange { face, index: *index as i64 });
+        }
+        current_index += count;
+    }
+
+    Ok(if has_faces {
+        let mut mesh = Polymesh::new(None, Mat4::IDENTITY, 1.0, egui::Color32::LIGHT_GRAY, points, indices, counts);
+        // colors only show on filled faces
+        if !colors.is_empty() || !face_colors.is_empty() { mesh = mesh.with_display(DisplayMode::Shaded); }
+        PlyShape::Polymesh(mesh.with_colors(colors).with_face_colors(face_colors))
+    } else {
+        PlyShape::PointCloud(PointCloud::new(None, Mat4::IDENTITY, 0.01, egui::Color32::LIGHT_GRAY, points).with_colors(colors))
+    })
+}
+
+pub fn load_ply(path: impl AsRef<Path>) -> Result<PlyShape, PlyError> {
+    let file = std::fs::File::open(path)?;
+    read_ply(std::io::BufReader::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mesh(shape: Result<PlyShape, PlyError>) -> Polymesh {
+        match shape {
+            Ok(PlyShape::Polymesh(mesh)) => mesh,
+            Ok(PlyShape::PointCloud(_)) => panic!("expected a polymesh, got a point cloud"),
+            Err(e) => panic!("{}", e),
+        }
+    }
+    fn point_cloud(shape: Result<PlyShape, PlyError>) -> PointCloud {
+        match shape {
+            Ok(PlyShape::PointCloud(cloud)) => cloud,
+            Ok(PlyShape::Polymesh(_)) => panic!("expected a point cloud, got a polymesh"),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    const HEADER: &str = "ply\nformat ascii 1.0\ncomment made by hand\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\n";
+    const TRIANGLE: &str = "0 0 0\n1 0 0\n0 1 0\n";
+
+    #[test]
+    fn ascii_faces_give_a_clockwise_polymesh() {
+        let ply = format!("{}element face 1\nproperty list uchar int vertex_indices\nend_header\n{}3 0 1 2\n", HEADER, TRIANGLE);
+        let mesh = mesh(read_ply(ply.as_bytes()));
+        assert_eq!(mesh.points(), vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        assert_eq!(mesh.indices(), vec![2, 1, 0]);
+        assert_eq!(mesh.counts(), vec![3]);
+        assert!(mesh.colors.is_empty() && mesh.face_colors.is_empty());
+        assert_eq!(mesh.display, DisplayMode::Wireframe);
+    }
+
+    #[test]
+    fn vertices_without_faces_give_a_point_cloud() {
+        let cloud = point_cloud(read_ply(format!("{}end_header\n{}", HEADER, TRIANGLE).as_bytes()));
+        assert_eq!(cloud.points(), vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+
+        // an empty face element doesn't make it a mesh
+        let ply = format!("{}element face 0\nproperty list uchar int vertex_indices\nend_header\n{}", HEADER, TRIANGLE);
+        assert_eq!(point_cloud(read_ply(ply.as_bytes())).points().len(), 3);
+    }
+
+    #[test]
+    fn binary_little_endian_is_read() {
+        let mut ply = b"ply\nformat binary_little_endian 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nelement face 1\nproperty list uchar uint vertex_indices\nend_header\n".to_vec();
+        for (p, rgb) in [(Vec3::ZERO, [255, 0, 0]), (Vec3::X, [0, 255, 0]), (Vec3::Y, [0, 0, 255])] {
+            for c in p.to_array() { ply.extend_from_slice(&c.to_le_bytes()); }
+            ply.extend_from_slice(&rgb);
+        }
+        ply.push(3);
+        for i in [0_u32, 1, 2] { ply.extend_from_slice(&i.to_le_bytes()); }
+
+        let mesh = mesh(read_ply(&ply[..]));
+        assert_eq!(mesh.points(), vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        assert_eq!(mesh.indices(), vec![2, 1, 0]);
+        assert_eq!(mesh.colors, vec![egui::Color32::RED, egui::Color32::GREEN, egui::Color32::BLUE]);
+        assert_eq!(mesh.display, DisplayMode::Shaded);
+    }
+
+    #[test]
+    fn vertex_and_face_colors_are_read() {
+        // float channels go 0..1, alpha is optional
+        let ply = "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nproperty float red\nproperty float green\nproperty float blue\nproperty float alpha\n\
+            element face 1\nproperty list uchar int vertex_indices\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nend_header\n\
+            0 0 0 1 0 0 1\n1 0 0 0 1 0 1\n0 1 0 0 0 1 0\n3 0 1 2 10 20 30\n";
+        let mesh = mesh(read_ply(ply.as_bytes()));
+        assert_eq!(mesh.colors, vec![egui::Color32::RED, egui::Color32::GREEN, egui::Color32::TRANSPARENT]);
+        assert_eq!(mesh.face_colors, vec![egui::Color32::from_rgb(10, 20, 30)]);
+        assert_eq!(mesh.display, DisplayMode::Shaded);
+
+        let ply = format!("{}property uchar red\nproperty uchar green\nproperty uchar blue\nend_header\n0 0 0 1 2 3\n1 0 0 4 5 6\n0 1 0 7 8 9\n", HEADER);
+        let cloud = point_cloud(read_ply(ply.as_bytes()));
+        assert_eq!(cloud.colors, vec![egui::Color32::from_rgb(1, 2, 3), egui::Color32::from_rgb(4, 5, 6), egui::Color32::from_rgb(7, 8, 9)]);
+    }
+
+    #[test]
+    fn unsupported_property_types_and_formats_are_errors() {
+        let ply = "ply\nformat ascii 1.0\nelement vertex 1\nproperty int128 x\nend_header\n";
+        match read_ply(ply.as_bytes()) {
+            Err(PlyError::UnsupportedPropertyType { element, property, type_name }) => {
+                assert_eq!((element.as_str(), property.as_str(), type_name.as_str()), ("vertex", "x", "int128"));
+            },
+            Err(e) => panic!("{}", e),
+            Ok(_) => panic!("read a property of an unknown type"),
+        }
+        let ply = "ply\nformat binary_big_endian 1.0\nelement vertex 0\nend_header\n";
+        assert!(matches!(read_ply(ply.as_bytes()), Err(PlyError::UnsupportedFormat(format)) if format == "binary_big_endian"));
+        assert!(matches!(read_ply("obj\n".as_bytes()), Err(PlyError::BadHeader(_))));
+    }
+
+    #[test]
+    fn bad_face_indices_are_errors() {
+        let face = |indices: &str| format!("{}element face 1\nproperty list uchar float vertex_indices\nend_header\n{}{}\n", HEADER, TRIANGLE, indices);
+        assert!(matches!(read_ply(face("3 0 1 3").as_bytes()), Err(PlyError::IndexOutOfRange { face: 0, index: 3 })));
+        assert!(matches!(read_ply(face("3 0 -1 2").as_bytes()), Err(PlyError::IndexOutOfRange { face: 0, index: -1 })));
+        // fractional indices would silently get truncated to another vertex
+        assert!(matches!(read_ply(face("3 0.9 1.5 2.7").as_bytes()), Err(PlyError::InvalidValue(_))));
+        assert_eq!(mesh(read_ply(face("3 0 1 2.0").as_bytes())).indices(), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn lists_longer_than_the_data_are_errors() {
+        // a count of 4 billion must fail without trying to make room for it
+        let mut ply = b"ply\nformat binary_little_endian 1.0\nelement face 1\nproperty list uint int vertex_indices\nend_header\n".to_vec();
+        ply.extend_from_slice(&u32::MAX.to_le_bytes());
+        ply.extend_from_slice(&[0; 12]);
+        assert!(matches!(read_ply(&ply[..]), Err(PlyError::UnexpectedEof)));
+    }
+
+    #[test]
+    fn truncated_bodies_are_errors() {
+        assert!(matches!(read_ply(format!("{}end_header\n0 0 0\n1 0 0\n0 1\n", HEADER).as_bytes()), Err(PlyError::UnexpectedEof)));
+        let mut ply = b"ply\nformat binary_little_endian 1.0\nelement vertex 2\nproperty float x\nproperty float y\nproperty float z\nend_header\n".to_vec();
+        ply.extend_from_slice(&[0; 20]);
+        assert!(matches!(read_ply(&ply[..]), Err(PlyError::UnexpectedEof)));
+        assert!(matches!(read_ply("ply\nformat ascii 1.0\nelement vertex 1\n".as_bytes()), Err(PlyError::UnexpectedEof)));
+    }
+}
@@ -0,0 +1,131 @@
+use glam::{Vec3, Mat4};
+
+use crate::camera::Camera;
+
+// the camera xform lives in a Y flipped space (see camera.rs), world positions have to be flipped to match
+const FLIP_Y: Vec3 = Vec3::new(1.0, -1.0, 1.0);
+
+// mouse bindings for orbit, pan and dolly, the scroll wheel dollies with every preset. Cameras that zoom, like
+// the orthographic one, get zoomed instead of dollied
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NavigationPreset {
+    Maya, // ALT + LMB orbit, ALT + MMB pan, ALT + RMB dolly
+    Blender, // MMB orbit, SHIFT + MMB pan, CTRL + MMB dolly
+    Turntable, // LMB orbit, MMB or RMB pan, no modifiers needed
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraController {
+    pub preset: NavigationPreset,
+    pub orbit_sensitivity: f32,
+    pub pan_sensitivity: f32,
+    pub dolly_sensitivity: f32,
+    pub pivot: Vec3, // world space point the camera orbits around and dollies towards, moves with panning
+    pub xform: Option<Mat4>, // current camera xform, taken from the camera the first time the controller is used
+    pub zoom: Option<f32>, // current zoom of cameras that zoom instead of dolly, taken from the camera like the xform
+}
+impl CameraController {
+    pub fn new(preset: NavigationPreset)->Self{
+        CameraController {
+            preset,
+            orbit_sensitivity: 0.01,
+            pan_sensitivity: 0.01,
+            dolly_sensitivity: 0.01,
+            pivot: Vec3::ZERO,
+            xform: None,
+            zoom: None,
+        }
+    }
+    pub fn with_sensitivity(mut self, orbit: f32, pan: f32, dolly: f32)->Self{
+        self.orbit_sensitivity = orbit;
+        self.pan_sensitivity = pan;
+        self.dolly_sensitivity = dolly;
+        self
+    }
+    pub fn with_pivot(mut self, pivot: Vec3)->Self{
+        self.pivot = pivot;
+        self
+    }
+    pub fn with_xform(mut self, xform: Mat4)->Self{
+        self.xform = Some(xform);
+        self
+    }
+
+    // reads the pointer input of the viewport response and moves the camera, returns true if the camera moved
+    pub fn update(&mut self, response: &egui::Response, camera: &mut dyn Camera) -> bool{
+        let mut xform = self.xform.unwrap_or(camera.get_xform());
+        let mut zoom = self.zoom.or(camera.get_zoom());
+        let (modifiers, scroll) = response.ctx.input(|i| (i.modifiers, i.scroll_delta.y));
+        let drag = response.drag_delta();
+        let primary = response.dragged_by(egui::PointerButton::Primary);
+        let middle = response.dragged_by(egui::PointerButton::Middle);
+        let secondary = response.dragged_by(egui::PointerButton::Secondary);
+
+        let (orbit, pan, dolly) = match self.preset {
+            NavigationPreset::Maya => (
+                modifiers.alt && primary,
+                modifiers.alt && middle,
+                modifiers.alt && secondary,
+            ),
+            NavigationPreset::Blender => (
+                middle && !modifiers.shift && !modifiers.ctrl,
+                middle && modifiers.shift,
+                middle && modifiers.ctrl,
+            ),
+            NavigationPreset::Turntable => (
+                primary,
+                middle || secondary,
+                false,
+            ),
+        };
+
+        if drag != egui::Vec2::ZERO {
+            if orbit { xform = self.orbit(xform, drag); }
+            if pan { xform = self.pan(xform, drag); }
+            if dolly { (xform, zoom) = self.dolly(xform, zoom, drag.x - drag.y); }
+        }
+        if response.hovered() && scroll != 0.0 {
+            (xform, zoom) = self.dolly(xform, zoom, scroll);
+        }
+
+        let moved = self.xform != Some(xform) || self.zoom != zoom;
+        self.xform = Some(xform);
+        self.zoom = zoom;
+        self.apply(camera);
+        moved
+    }
+    // puts the camera where the controller left it without reading any input
+    pub fn apply(&self, camera: &mut dyn Camera){
+        if let Some(xform) = self.xform { camera.set_xform(xform); }
+        if let Some(zoom) = self.zoom { camera.set_zoom(zoom); }
+    }
+
+    fn orbit(&self, xform: Mat4, delta: egui::Vec2) -> Mat4{
+        let pivot = self.pivot * FLIP_Y;
+        let rot_y = Mat4::from_rotation_y(delta.x * self.orbit_sensitivity);
+        let x_axis = xform.x_axis.truncate().normalize();
+        let rot_x = Mat4::from_axis_angle(x_axis, delta.y * self.orbit_sensitivity);
+        Mat4::from_translation(pivot) * rot_y * rot_x * Mat4::from_translation(-pivot) * xform
+    }
+
+    fn pan(&mut self, xform: Mat4, delta: egui::Vec2) -> Mat4{
+        let offset = xform.x_axis.truncate() * -delta.x * self.pan_sensitivity
+            + xform.y_axis.truncate() * -delta.y * self.pan_sensitivity;
+        self.pivot += offset * FLIP_Y; // keep orbiting around the same spot on screen
+        Mat4::from_translation(offset) * xform
+    }
+
+    // Cameras that zoom stay where they are and zoom in as much as moving closer would have made things bigger,
+    // an orthographic camera looks the same from any distance
+    fn dolly(&self, xform: Mat4, zoom: Option<f32>, amount: f32) -> (Mat4, Option<f32>){
+        let pivot = self.pivot * FLIP_Y;
+        let to_pivot = pivot - xform.transform_point3(Vec3::ZERO);
+        let dist = to_pivot.length();
+        if dist == 0.0 { return (xform, zoom); }
+        let step = (amount * self.dolly_sensitivity).min(dist - 0.01); // never dolly through the pivot
+        match zoom {
+            Some(zoom) => (xform, Some(zoom * dist / (dist - step))),
+            None => (Mat4::from_translation(to_pivot / dist * step) * xform, None),
+        }
+    }
+}
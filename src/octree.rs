@@ -0,0 +1,206 @@
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+use glam::{Vec3, Mat4};
+
+use crate::bounds::Aabb;
+use crate::clipping::{self, ClipPlanes};
+
+const LEAF_SIZE: usize = 256; // most points a leaf holds, also how many points a coarse node draws for all of its children
+const MAX_DEPTH: usize = 16; // stops splitting piles of duplicate points
+
+#[derive(Clone, Debug)]
+pub struct OctreeNode {
+    pub bounds: Aabb, // cube the node covers
+    pub points: Range<usize>, // its points, a range of Octree::indices
+    pub children: Range<usize>, // the non empty octants, a range of Octree::nodes, empty for leaves
+}
+impl OctreeNode {
+    pub fn is_leaf(&self) -> bool{
+        self.children.is_empty()
+    }
+    // number of points the node draws when it is not refined any further
+    fn sample_len(&self) -> usize{
+        self.points.len().min(LEAF_SIZE)
+    }
+}
+
+// Octree over a set of points, it only stores indices so the points stay where they are.
+// Points are ordered octant by octant, so every node covers a contiguous range of indices
+// and an even stride through that range gives a sample spread over the whole node
+#[derive(Clone, Debug)]
+pub struct Octree {
+    pub nodes: Vec<OctreeNode>, // root first
+    pub indices: Vec<usize>,
+}
+impl Octree {
+    pub fn new(points: &[Vec3])->Self{
+        let mut octree = Octree {
+            nodes: Vec::new(),
+            indices: (0..points.len()).collect(),
+        };
+        let Some(bounds) = Aabb::from_points(points.iter().copied().filter(|p| p.is_finite())) else { return octree; };
+        // cubic so the octants stay cubes too
+        let half = bounds.size().max_element() / 2.0;
+        octree.nodes.push(OctreeNode {
+            bounds: Aabb::around(bounds.center(), half),
+            points: 0..points.len(),
+            children: 0..0,
+        });
+        octree.split(points, 0, 0);
+        octree
+    }
+    pub fn len(&self) -> usize{
+        self.indices.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.indices.is_empty()
+    }
+
+    fn split(&mut self, points: &[Vec3], node: usize, depth: usize){
+        let range = self.nodes[node].points.clone();
+        if range.len() <= LEAF_SIZE || depth >= MAX_DEPTH { return; }
+        let bounds = self.nodes[node].bounds;
+        let center = bounds.center();
+        let octant = |i: &usize| {
+            let p = points[*i];
+            (p.x >= center.x) as usize | ((p.y >= center.y) as usize) << 1 | ((p.z >= center.z) as usize) << 2
+        };
+        self.indices[range.clone()].sort_by_key(octant);
+
+        let first_child = self.nodes.len();
+        let mut start = range.start;
+        while start < range.end {
+            let o = octant(&self.indices[start]);
+            let end = start + self.indices[start..range.end].iter().take_while(|i| octant(i) == o).count();
+            let min = Vec3::new(
+                if o & 1 == 0 { bounds.min.x } else { center.x },
+                if o & 2 == 0 { bounds.min.y } else { center.y },
+                if o & 4 == 0 { bounds.min.z } else { center.z },
+            );
+            self.nodes.push(OctreeNode {
+                bounds: Aabb::new(min, min + (center - bounds.min)),
+                points: start..end,
+                children: 0..0,
+            });
+            start = end;
+        }
+        self.nodes[node].children = first_child..self.nodes.len();
+        for child in first_child..self.nodes.len() {
+            self.split(points, child, depth + 1);
+        }
+    }
+
+    // Level of detail selection, the indices of the points to draw this frame. `screen_size` gives the size of a
+    // node's bounds on screen in points, None for nodes outside the view. Starting at the root the biggest nodes
+    // on screen get refined into their children until their sampled points are at most `min_spacing` apart on
+    // screen, or refining any further would draw more than `budget` points. It never returns more than `budget` points
+    pub fn lod(&self, budget: usize, min_spacing: f32, mut screen_size: impl FnMut(&Aabb) -> Option<f32>) -> Vec<usize>{
+        let Some(root) = self.nodes.first() else { return Vec::new(); };
+        let Some(root_size) = screen_size(&root.bounds) else { return Vec::new(); };
+
+        // sizes are never negative, so ordering them by their bits orders them by value
+        let mut queue: BinaryHeap<(u32, usize)> = BinaryHeap::new();
+        queue.push((root_size.max(0.0).to_bits(), 0));
+        let mut count = root.sample_len();
+        let mut cut: Vec<usize> = Vec::new();
+        while let Some((size, node_i)) = queue.pop() {
+            let node = &self.nodes[node_i];
+            let spacing = f32::from_bits(size) / (node.sample_len() as f32).sqrt();
+            if node.is_leaf() || spacing <= min_spacing {
+                cut.push(node_i);
+                continue;
+            }
+            let children: Vec<(u32, usize)> = node.children.clone()
+                .filter_map(|c| screen_size(&self.nodes[c].bounds).map(|s| (s.max(0.0).to_bits(), c)))
+                .collect();
+            let refined_count = count - node.sample_len() + children.iter().map(|(_, c)| self.nodes[*c].sample_len()).sum::<usize>();
+            if refined_count > budget {
+                cut.push(node_i);
+                continue;
+            }
+            count = refined_count;
+            queue.extend(children);
+        }
+
+        // The root alone, or a node that can't be refined, may hold more samples than the budget allows.
+        // Every cut node then gives up the same share of its samples, the running totals keep the sum exact
+        let keep = count.min(budget);
+        let mut selected = Vec::with_capacity(keep);
+        let mut sampled = 0;
+        for node in cut.iter().map(|n| &self.nodes[*n]) {
+            let (len, start) = (node.points.len(), sampled * keep / count.max(1));
+            sampled += node.sample_len();
+            let n = sampled * keep / count.max(1) - start;
+            selected.extend((0..n).map(|i| self.indices[node.points.start + i * len / n]));
+        }
+        selected
+    }
+
+    // The queries take the points the octree was built from
+
+    // Point closest to the ray origin that lies within `radius` of the ray, with its ray parameter
+    pub fn ray(&self, points: &[Vec3], origin: Vec3, dir: Vec3, radius: f32) -> Option<(usize, f32)>{
+        let dir_len_sq = dir.length_squared();
+        if dir_len_sq == 0.0 { return None; }
+        let mut closest: Option<(usize, f32)> = None;
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            let Some(t_enter) = node.bounds.expanded(radius).ray(origin, dir) else { continue; };
+            if closest.is_some_and(|(_, t)| t_enter > t) { continue; }
+            if node.is_leaf() {
+                for i in self.indices[node.points.clone()].iter() {
+                    let t = (points[*i] - origin).dot(dir) / dir_len_sq;
+                    if t < 0.0 || (origin + dir * t - points[*i]).length() > radius { continue; }
+                    if closest.map_or(true, |(_, closest_t)| t < closest_t) { closest = Some((*i, t)); }
+                }
+            } else {
+                stack.extend(node.children.clone());
+            }
+        }
+        closest
+    }
+
+    // points inside the view frustum of `view_projection`, in octree order
+    pub fn frustum(&self, points: &[Vec3], view_projection: &Mat4) -> Vec<usize>{
+        let mut found = Vec::new();
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            let corners = node.bounds.corners().map(|c| clipping::to_clip(view_projection, c));
+            if ClipPlanes::Frustum.excludes(&corners) { continue; }
+            let indices = &self.indices[node.points.clone()];
+            if corners.iter().all(|c| ClipPlanes::Frustum.contains(*c)) {
+                found.extend_from_slice(indices);
+            } else if node.is_leaf() {
+                found.extend(indices.iter().filter(|i| ClipPlanes::Frustum.contains(clipping::to_clip(view_projection, points[**i]))));
+            } else {
+                stack.extend(node.children.clone().rev());
+            }
+        }
+        found
+    }
+
+    // point closest to p, with its distance
+    pub fn nearest(&self, points: &[Vec3], p: Vec3) -> Option<(usize, f32)>{
+        let mut closest: Option<(usize, f32)> = None; // squared distance while searching
+        let mut stack = vec![0];
+        while let Some(node_i) = stack.pop() {
+            let Some(node) = self.nodes.get(node_i) else { continue; };
+            if closest.is_some_and(|(_, d)| node.bounds.distance_squared(p) > d) { continue; }
+            if node.is_leaf() {
+                for i in self.indices[node.points.clone()].iter() {
+                    let d = (points[*i] - p).length_squared();
+                    if closest.map_or(true, |(_, closest_d)| d < closest_d) { closest = Some((*i, d)); }
+                }
+            } else {
+                // visit the closest octant first so the others are more likely to get skipped
+                let mut children: Vec<usize> = node.children.clone().collect();
+                children.sort_by(|a, b| self.nodes[*b].bounds.distance_squared(p).total_cmp(&self.nodes[*a].bounds.distance_squared(p)));
+                stack.extend(children);
+            }
+        }
+        closest.map(|(i, d)| (i, d.sqrt()))
+    }
+}
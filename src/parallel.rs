@@ -0,0 +1,17 @@
+// Loops over big shapes that run on the rayon thread pool with the `rayon` feature and serially without it.
+// The results are the same either way, in the same order
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+const CHUNK: usize = 4096; // items per task, shapes with fewer are not worth spreading over threads
+
+// f applied to every item, in order. Serially inside a pool of a single thread, where tasks would only add overhead
+pub(crate) fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync + Send) -> Vec<U> {
+    #[cfg(feature = "rayon")]
+    if items.len() > CHUNK && rayon::current_num_threads() > 1 {
+        return items.par_iter().with_min_len(CHUNK).map(f).collect();
+    }
+    items.iter().map(f).collect()
+}
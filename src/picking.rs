@@ -0,0 +1,160 @@
+use glam::{Vec3, Vec4, Mat4};
+
+use crate::camera::Camera;
+use crate::clipping::{self, ClipPlanes};
+use crate::scene::NodeId;
+
+// result of picking the viewport at the pointer position
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pick {
+    pub node: NodeId, // scene node of the picked shape
+    pub name: String,
+    pub position: Vec3, // world space position of the hit
+}
+
+// everything a shape needs to test itself against the pointer
+pub struct PickQuery {
+    pub rect: egui::Rect, // viewport rect on screen
+    pub pointer: egui::Pos2,
+    pub ray_origin: Vec3, // world space ray through the pointer
+    pub ray_dir: Vec3,
+    pub tolerance: f32, // max screen distance in points for lines and points
+}
+impl PickQuery {
+    pub fn new(cam: &dyn Camera, rect: egui::Rect, pointer: egui::Pos2)->Self{
+        let (ray_origin, ray_dir) = cam.screen_ray(pointer, rect);
+        PickQuery {
+            rect,
+            pointer,
+            ray_origin,
+            ray_dir,
+            tolerance: 6.0,
+        }
+    }
+}
+
+// world space point under the pointer if it is within `radius` points of the projected point
+pub fn pick_point(cam: &dyn Camera, query: &PickQuery, p: Vec3, radius: f32) -> Option<Vec3> {
+    let (pos, _) = clipping::world_to_screen(&cam.view_projection(), query.rect, p)?;
+    let dist = (pos - query.pointer).length();
+    (dist <= radius.max(query.tolerance)).then_some(p)
+}
+
+// View projection that only keeps the square of `radius` points around the pointer in its frustum,
+// for finding the candidates of a pick in a spatial index before testing them one by one
+pub fn pick_region(cam: &dyn Camera, query: &PickQuery, radius: f32) -> Mat4 {
+    let offset = query.pointer - query.rect.center();
+    let (nx, ny) = (offset.x / query.rect.width() * 2.0, offset.y / query.rect.height() * 2.0);
+    let (rx, ry) = (radius / query.rect.width() * 2.0, radius / query.rect.height() * 2.0);
+    let region = Mat4::from_cols(
+        Vec4::new(1.0 / rx, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, 1.0 / ry, 0.0, 0.0),
+        Vec4::Z,
+        Vec4::new(-nx / rx, -ny / ry, 0.0, 1.0),
+    );
+    region * cam.view_projection()
+}
+
+// closest point of the world space segment a-b under the pointer if its projection is within the tolerance
+pub fn pick_segment(cam: &dyn Camera, query: &PickQuery, a: Vec3, b: Vec3) -> Option<Vec3> {
+    let view_projection = cam.view_projection();
+    let (clip_a, clip_b) = clipping::clip_segment(clipping::to_clip(&view_projection, a), clipping::to_clip(&view_projection, b), ClipPlanes::Near)?;
+    let (screen_a, screen_b) = (clipping::clip_to_screen(query.rect, clip_a).0, clipping::clip_to_screen(query.rect, clip_b).0);
+    let ab = screen_b - screen_a;
+    let t = if ab.length_sq() > 0.0 { ((query.pointer - screen_a).dot(ab) / ab.length_sq()).clamp(0.0, 1.0) } else { 0.0 };
+    if (screen_a + ab * t - query.pointer).length() > query.tolerance { return None; }
+    // interpolate in clip space so the world position stays correct under perspective
+    let hit_clip = clip_a.lerp(clip_b, t);
+    Some(view_projection.inverse().project_point3(hit_clip.truncate() / hit_clip.w))
+}
+
+// closest point of a world space polyline under the pointer
+pub fn pick_polyline(cam: &dyn Camera, query: &PickQuery, pts: &[Vec3]) -> Option<Vec3> {
+    pts.windows(2)
+        .filter_map(|s| pick_segment(cam, query, s[0], s[1]))
+        .min_by(|a, b| cam.dist_to_point(*a).total_cmp(&cam.dist_to_point(*b)))
+}
+
+// ray parameter of the hit with a box of the given size centered in the origin of xform
+pub fn ray_box(origin: Vec3, dir: Vec3, xform: Mat4, size: Vec3) -> Option<f32> {
+    // work in the local space of the box, the ray parameter stays the same as dir is not normalized again
+    let inv = xform.inverse();
+    let o = inv.transform_point3(origin);
+    let d = inv.transform_vector3(dir);
+    let (mut t_min, mut t_max) = (f32::NEG_INFINITY, f32::INFINITY);
+    for axis in 0..3 {
+        let half = size[axis] / 2.0;
+        if d[axis].abs() < f32::EPSILON {
+            if o[axis].abs() > half { return None; }
+            continue;
+        }
+        let t1 = (-half - o[axis]) / d[axis];
+        let t2 = (half - o[axis]) / d[axis];
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+    }
+    if t_max < t_min.max(0.0) { return None; }
+    Some(t_min.max(0.0))
+}
+
+// ray parameter of the hit with an infinite plane, e.g. the ground plane of the grid for placing things with the mouse
+pub fn ray_plane(origin: Vec3, dir: Vec3, plane_point: Vec3, plane_normal: Vec3) -> Option<f32> {
+    let denom = dir.dot(plane_normal);
+    if denom == 0.0 { return None; }
+    let t = (plane_point - origin).dot(plane_normal) / denom;
+    (t >= 0.0).then_some(t)
+}
+
+// Moller-Trumbore ray triangle intersection, both faces count
+pub fn ray_triangle(origin: Vec3, dir: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+    let ab = b - a;
+    let ac = c - a;
+    let p = dir.cross(ac);
+    let det = ab.dot(p);
+    if det == 0.0 { return None; }
+    let inv_det = 1.0 / det;
+    let ao = origin - a;
+    let u = ao.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) { return None; }
+    let q = ao.cross(ab);
+    let v = dir.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 { return None; }
+    let t = ac.dot(q) * inv_det;
+    (t >= 0.0).then_some(t)
+}
+
+// ray parameter of the closest hit with a sphere, or 0 if the ray starts inside it
+pub fn ray_sphere(origin: Vec3, dir: Vec3, center: Vec3, radius: f32) -> Option<f32> {
+    let oc = origin - center;
+    let a = dir.dot(dir);
+    let b = oc.dot(dir);
+    let c = oc.dot(oc) - radius * radius;
+    let discriminant = b * b - a * c;
+    if a == 0.0 || discriminant < 0.0 { return None; }
+    let sqrt_d = discriminant.sqrt();
+    let (t1, t2) = ((-b - sqrt_d) / a, (-b + sqrt_d) / a);
+    if t2 < 0.0 { return None; }
+    Some(t1.max(0.0))
+}
+
+// point of the triangle closest to p, from Real-Time Collision Detection by Christer Ericson
+pub fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 { return a; }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 { return b; }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 { return a + ab * (d1 / (d1 - d3)); }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 { return c; }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 { return a + ac * (d2 / (d2 - d6)); }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 { return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6))); }
+    let denom = va + vb + vc;
+    if denom == 0.0 { return a; } // degenerate triangle
+    a + ab * (vb / denom) + ac * (vc / denom)
+}
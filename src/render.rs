@@ -0,0 +1,41 @@
+use crate::camera::Camera;
+use crate::scene::{NodeId, Scene};
+use crate::draw_list::{DrawList, DepthSort};
+use crate::draw_context::{DrawContext, DrawStyle};
+use crate::lighting::Lighting;
+
+// draws every visible node inside the view into a draw list, returns the drawn nodes in drawing order
+pub(crate) fn draw_scene(scene: &Scene, cam: &dyn Camera, rect: egui::Rect, style: DrawStyle) -> (DrawList, Vec<NodeId>) {
+    // gather the lights first so every shape gets shaded by all of them
+    let mut lighting = Lighting::new();
+    scene.visit_visible(|_, node, xform| {
+        if let Some(light) = node.shape.as_ref().and_then(|sh| sh.light()) {
+            lighting.lights.push(light.transformed(xform));
+        }
+    });
+
+    let mut draw_list = DrawList::new();
+    let mut drawn: Vec<NodeId> = Vec::new();
+    let mut ctx = DrawContext::new(cam, rect, &mut draw_list).with_style(style);
+    ctx.lighting = lighting;
+    scene.visit_visible(|id, node, xform| {
+        let Some(sh) = &node.shape else { return; };
+        ctx.set_parent_xform(xform);
+        if sh.bounds().is_some_and(|b| !ctx.is_visible(&b)) { return; } // frustum culling
+        drawn.push(id);
+        ctx.out.begin_object(id.index());
+        sh.draw(&mut ctx);
+    });
+    (draw_list, drawn)
+}
+
+// Renders the scene without a window, the same shapes Viewport3d would paint into `rect`.
+// Labels get laid out with the default egui fonts, those are only loaded when there is a label to draw
+pub fn render_scene(scene: &Scene, camera: &dyn Camera, rect: egui::Rect, depth_sort: DepthSort, style: DrawStyle) -> Vec<egui::Shape> {
+    let mut camera = camera.box_clone();
+    camera.set_aspect_ratio(rect.aspect_ratio());
+    let (mut draw_list, _) = draw_scene(scene, camera.as_ref(), rect, style);
+    draw_list.sort(depth_sort);
+    let fonts = draw_list.has_labels().then(|| egui::epaint::Fonts::new(1.0, 2048, egui::FontDefinitions::default()));
+    draw_list.into_shapes(fonts.as_ref())
+}
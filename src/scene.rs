@@ -0,0 +1,166 @@
+use glam::Mat4;
+
+use crate::Shape3d;
+
+// stable handle of a node, stays valid (and is never reused) when other nodes get removed
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+impl NodeId {
+    pub fn index(&self) -> usize{
+        self.0
+    }
+}
+
+pub struct Node {
+    pub name: String,
+    pub xform: Mat4, // local transform, relative to the parent node
+    pub shape: Option<Box<dyn Shape3d>>, // drawn with its own xform on top of the node transform
+    pub visible: bool, // hides the node together with all of its children
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+impl Node {
+    pub fn new(name: impl Into<String>)->Self{
+        Node {
+            name: name.into(),
+            xform: Mat4::IDENTITY,
+            shape: None,
+            visible: true,
+            parent: None,
+            children: Vec::new(),
+        }
+    }
+    pub fn with_xform(mut self, xform: Mat4)->Self{
+        self.xform = xform;
+        self
+    }
+    pub fn with_shape(mut self, shape: Box<dyn Shape3d>)->Self{
+        self.shape = Some(shape);
+        self
+    }
+    pub fn with_visible(mut self, visible: bool)->Self{
+        self.visible = visible;
+        self
+    }
+    pub fn parent(&self) -> Option<NodeId>{
+        self.parent
+    }
+    pub fn children(&self) -> &[NodeId]{
+        &self.children
+    }
+}
+
+// hierarchy of nodes, kept in the app state and borrowed by the viewport every frame
+pub struct Scene {
+    nodes: Vec<Option<Node>>, // removed nodes leave a hole so the ids of the others stay the same
+    roots: Vec<NodeId>,
+}
+impl Scene {
+    pub fn new()->Self{
+        Scene {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        }
+    }
+    // adds the shape as a new root node named after the shape
+    pub fn add(&mut self, shape: Box<dyn Shape3d>) -> NodeId{
+        let name = shape.name().map(|n| n.to_string()).unwrap_or(format!("node{}", self.nodes.len()));
+        self.add_node(None, Node::new(name).with_shape(shape))
+    }
+    pub fn add_node(&mut self, parent: Option<NodeId>, mut node: Node) -> NodeId{
+        let id = NodeId(self.nodes.len());
+        let parent = parent.filter(|p| self.node(*p).is_some());
+        node.parent = parent;
+        node.children.clear();
+        self.nodes.push(Some(node));
+        match parent {
+            Some(p) => self.nodes[p.0].as_mut().unwrap().children.push(id),
+            None => self.roots.push(id),
+        }
+        id
+    }
+    // removes the node and all of its children
+    pub fn remove(&mut self, id: NodeId) -> Option<Node>{
+        let node = self.nodes.get_mut(id.0)?.take()?;
+        match node.parent.and_then(|p| self.nodes[p.0].as_mut()) {
+            Some(parent) => parent.children.retain(|c| *c != id),
+            None => self.roots.retain(|r| *r != id),
+        }
+        for child in node.children.iter() {
+            self.remove_subtree(*child);
+        }
+        Some(node)
+    }
+    fn remove_subtree(&mut self, id: NodeId){
+        if let Some(node) = self.nodes[id.0].take() {
+            for child in node.children {
+                self.remove_subtree(child);
+            }
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&Node>{
+        self.nodes.get(id.0).and_then(|n| n.as_ref())
+    }
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut Node>{
+        self.nodes.get_mut(id.0).and_then(|n| n.as_mut())
+    }
+    pub fn roots(&self) -> &[NodeId]{
+        &self.roots
+    }
+    // first node with the given name
+    pub fn find(&self, name: &str) -> Option<NodeId>{
+        self.iter().find(|(_, node)| node.name == name).map(|(id, _)| id)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)>{
+        self.nodes.iter().enumerate().filter_map(|(i, n)| n.as_ref().map(|n| (NodeId(i), n)))
+    }
+    pub fn len(&self) -> usize{
+        self.iter().count()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.iter().next().is_none()
+    }
+
+    // transform of the node in world space, all parent transforms applied
+    pub fn world_xform(&self, id: NodeId) -> Mat4{
+        let mut xform = Mat4::IDENTITY;
+        let mut current = self.node(id);
+        while let Some(node) = current {
+            xform = node.xform * xform;
+            current = node.parent.and_then(|p| self.node(p));
+        }
+        xform
+    }
+    // calls f for every visible node, parents before children, with the composed world transform
+    pub fn visit_visible(&self, mut f: impl FnMut(NodeId, &Node, Mat4)){
+        for root in self.roots.iter() {
+            self.visit_node(*root, Mat4::IDENTITY, &mut f);
+        }
+    }
+    fn visit_node(&self, id: NodeId, parent_xform: Mat4, f: &mut impl FnMut(NodeId, &Node, Mat4)){
+        let Some(node) = self.node(id) else { return; };
+        if !node.visible { return; }
+        let xform = parent_xform * node.xform;
+        f(id, node, xform);
+        for child in node.children.iter() {
+            self.visit_node(*child, xform, f);
+        }
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Vec<Box<dyn Shape3d>>> for Scene {
+    fn from(shapes: Vec<Box<dyn Shape3d>>) -> Self {
+        let mut scene = Scene::new();
+        for shape in shapes {
+            scene.add(shape);
+        }
+        scene
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+
+use crate::scene::{NodeId, Scene};
+
+// set of selected scene nodes, kept in the app state and borrowed by the viewport
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+    nodes: BTreeSet<NodeId>,
+}
+impl Selection {
+    pub fn new()->Self{
+        Selection {
+            nodes: BTreeSet::new(),
+        }
+    }
+    pub fn contains(&self, id: NodeId) -> bool{
+        self.nodes.contains(&id)
+    }
+    // returns false if the node was already selected
+    pub fn insert(&mut self, id: NodeId) -> bool{
+        self.nodes.insert(id)
+    }
+    pub fn remove(&mut self, id: NodeId) -> bool{
+        self.nodes.remove(&id)
+    }
+    pub fn toggle(&mut self, id: NodeId){
+        if !self.nodes.remove(&id) {
+            self.nodes.insert(id);
+        }
+    }
+    // replaces the selection with a single node
+    pub fn select(&mut self, id: NodeId){
+        self.nodes.clear();
+        self.nodes.insert(id);
+    }
+    pub fn clear(&mut self){
+        self.nodes.clear();
+    }
+    pub fn iter(&self) -> impl Iterator<Item = NodeId> + '_{
+        self.nodes.iter().copied()
+    }
+    pub fn len(&self) -> usize{
+        self.nodes.len()
+    }
+    pub fn is_empty(&self) -> bool{
+        self.nodes.is_empty()
+    }
+    // true if the node or any of its parents is selected, children get highlighted with their parent
+    pub fn covers(&self, scene: &Scene, id: NodeId) -> bool{
+        let mut current = Some(id);
+        while let Some(node_id) = current {
+            if self.contains(node_id) { return true; }
+            current = scene.node(node_id).and_then(|n| n.parent());
+        }
+        false
+    }
+}
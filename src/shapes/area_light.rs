@@ -0,0 +1,94 @@
+use egui;
+use glam::{Vec3, Mat4};
+
+use super::Shape3d;
+use super::point_light::icon_stroke;
+use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+use crate::lighting::{Light, Falloff};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AreaLightShape {
+    Rect { width: f32, height: f32 },
+    Disk { radius: f32 },
+}
+
+// light emitting from a surface in the xy plane of xform, towards -z
+#[derive(Clone)]
+pub struct AreaLight {
+    pub name: Option<String>,
+    pub xform: Mat4,
+    pub shape: AreaLightShape,
+    pub color: egui::Color32, // color of the light it casts, the icon is drawn like the point light one
+    pub intensity: f32,
+    pub falloff: Falloff,
+}
+impl AreaLight {
+    pub fn new(name: Option<String>, xform: Mat4, shape: AreaLightShape, color: egui::Color32)->AreaLight{
+        AreaLight {
+            name,
+            xform,
+            shape,
+            color,
+            intensity: 1.0,
+            falloff: Falloff::None,
+        }
+    }
+    pub fn with_intensity(mut self, intensity: f32)->Self{
+        self.intensity = intensity;
+        self
+    }
+    pub fn with_falloff(mut self, falloff: Falloff)->Self{
+        self.falloff = falloff;
+        self
+    }
+
+    // outline of the surface, a cross for the rect and a line along the light direction
+    fn polylines(&self) -> Vec<Vec<Vec3>>{
+        let xf = |p: Vec3| self.xform.transform_point3(p);
+        let (mut lines, normal_length) = match self.shape {
+            AreaLightShape::Rect { width, height } => {
+                let (w, h) = (width / 2.0, height / 2.0);
+                let corners = [Vec3::new(-w, -h, 0.0), Vec3::new(w, -h, 0.0), Vec3::new(w, h, 0.0), Vec3::new(-w, h, 0.0)];
+                let outline = corners.iter().chain(corners.first()).map(|p| xf(*p)).collect();
+                (vec![outline, vec![xf(corners[0]), xf(corners[2])], vec![xf(corners[1]), xf(corners[3])]], width.max(height) * 0.5)
+            },
+            AreaLightShape::Disk { radius } => {
+                (vec![super::circle_points(self.xform, radius, 0.0, 32)], radius)
+            },
+        };
+        lines.push(vec![xf(Vec3::ZERO), xf(-Vec3::Z * normal_length)]);
+        lines
+    }
+}
+
+impl Shape3d for AreaLight{
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let stroke = icon_stroke(ctx, pivot);
+        for line in self.polylines() {
+            ctx.polyline(&line, stroke);
+        }
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), stroke.color);
+        }
+    }
+
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        self.polylines().iter()
+            .filter_map(|line| picking::pick_polyline(cam, query, line))
+            .min_by(|a, b| cam.dist_to_point(*a).total_cmp(&cam.dist_to_point(*b)))
+    }
+    fn light(&self) -> Option<Light>{
+        let area = match self.shape {
+            AreaLightShape::Rect { width, height } => width * height,
+            AreaLightShape::Disk { radius } => std::f32::consts::PI * radius * radius,
+        };
+        let (position, direction) = (self.xform.transform_point3(Vec3::ZERO), self.xform.transform_vector3(-Vec3::Z));
+        Some(Light::area(position, direction, area, self.color, self.intensity, self.falloff))
+    }
+}
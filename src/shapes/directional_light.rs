@@ -0,0 +1,76 @@
+use egui;
+use glam::{Vec3, Mat4};
+
+use super::Shape3d;
+use super::point_light::icon_stroke;
+use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+use crate::lighting::Light;
+
+// light coming from infinitely far away, shining down the -z axis of xform
+#[derive(Clone)]
+pub struct DirectionalLight {
+    pub name: Option<String>,
+    pub xform: Mat4,
+    pub size: f32, // length of the arrows
+    pub color: egui::Color32, // color of the light it casts, the icon is drawn like the point light one
+    pub intensity: f32,
+}
+impl DirectionalLight {
+    pub fn new(name: Option<String>, xform: Mat4, size: f32, color: egui::Color32)->DirectionalLight{
+        DirectionalLight {
+            name,
+            xform,
+            size,
+            color,
+            intensity: 1.0,
+        }
+    }
+    pub fn with_intensity(mut self, intensity: f32)->Self{
+        self.intensity = intensity;
+        self
+    }
+
+    // a disk with parallel arrows pointing along the light direction
+    fn polylines(&self) -> Vec<Vec<Vec3>>{
+        let radius = self.size * 0.3;
+        let head = self.size * 0.15;
+        let mut lines = vec![super::circle_points(Mat4::IDENTITY, radius, 0.0, 32)];
+        let starts = [Vec3::ZERO, Vec3::X * radius, -Vec3::X * radius, Vec3::Y * radius, -Vec3::Y * radius];
+        for start in starts {
+            let tip = start - Vec3::Z * self.size;
+            lines.push(vec![start, tip]);
+            lines.push(vec![tip + Vec3::new(head * 0.5, 0.0, head), tip, tip + Vec3::new(-head * 0.5, 0.0, head)]);
+        }
+        for line in lines.iter_mut() {
+            for p in line.iter_mut() { *p = self.xform.transform_point3(*p); }
+        }
+        lines
+    }
+}
+
+impl Shape3d for DirectionalLight{
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let stroke = icon_stroke(ctx, pivot);
+        for line in self.polylines() {
+            ctx.polyline(&line, stroke);
+        }
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), stroke.color);
+        }
+    }
+
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        self.polylines().iter()
+            .filter_map(|line| picking::pick_polyline(cam, query, line))
+            .min_by(|a, b| cam.dist_to_point(*a).total_cmp(&cam.dist_to_point(*b)))
+    }
+    fn light(&self) -> Option<Light>{
+        Some(Light::directional(self.xform.transform_vector3(-Vec3::Z), self.color, self.intensity))
+    }
+}
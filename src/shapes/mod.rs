@@ -1,13 +1,64 @@
 pub mod cube;
 pub mod grid;
 pub mod point_light;
+pub mod directional_light;
+pub mod spot_light;
+pub mod area_light;
 pub mod point_cloud;
 pub mod polymesh;
+pub mod sphere;
 pub mod vector;
-use egui;
+use crate::bounds::Aabb;
 use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::PickQuery;
+use crate::lighting::Light;
+use glam::{Vec3, Mat4};
 
 
 pub trait Shape3d {
-    fn draw(&self, ui: &mut egui::Ui,  cam: &dyn Camera, painter: &egui::Painter, response: &egui::Response);
-}
\ No newline at end of file
+    fn draw(&self, ctx: &mut DrawContext<'_>);
+    fn name(&self) -> Option<&str>{
+        None
+    }
+    // Box around everything the shape draws, in the same space the shape draws in (its own xform applied).
+    // Shapes whose bounds are outside the view don't get drawn, None always draws the shape
+    fn bounds(&self) -> Option<Aabb>{
+        None
+    }
+    // world space position of the shape under the pointer, None if it is missed or the shape can't be picked
+    fn pick(&self, _cam: &dyn Camera, _query: &PickQuery) -> Option<Vec3>{
+        None
+    }
+    // light cast by the shape, in the same space the shape draws in
+    fn light(&self) -> Option<Light>{
+        None
+    }
+}
+
+// how shapes with faces get drawn
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DisplayMode {
+    Wireframe, // face outlines faded by how much they face the camera
+    Shaded, // filled faces lit by the scene lights, faces turned away from the camera are culled
+    ShadedWireframe, // filled faces with the outlines on top
+}
+
+// Newell normal of a polygon, robust for faces with more than 3 points or a few collinear ones.
+// Faces wound clockwise point towards the viewer
+pub(crate) fn face_normal(pts: &[Vec3]) -> Vec3 {
+    let mut n = Vec3::ZERO;
+    for i in 0..pts.len() {
+        let (a, b) = (pts[i], pts[(i + 1) % pts.len()]);
+        n += Vec3::new((a.y - b.y) * (a.z + b.z), (a.z - b.z) * (a.x + b.x), (a.x - b.x) * (a.y + b.y));
+    }
+    -n.normalize_or_zero()
+}
+
+// points of a closed circle in the xy plane of xform, at depth z
+pub(crate) fn circle_points(xform: Mat4, radius: f32, z: f32, segments: usize) -> Vec<Vec3> {
+    (0..=segments).map(|i| {
+        let a = i as f32 / segments as f32 * std::f32::consts::TAU;
+        xform.transform_point3(Vec3::new(a.cos() * radius, a.sin() * radius, z))
+    }).collect()
+}
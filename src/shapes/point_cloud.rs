@@ -1,8 +1,17 @@
-use egui::{self, Response};
-use glam::{Vec3, Mat4, Quat};
+use std::sync::Arc;
+
+use egui;
+use glam::{Vec3, Mat4};
 
 use super::Shape3d;
+use crate::bounds::Aabb;
 use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::clipping;
+use crate::picking::{self, PickQuery};
+use crate::colormap::ScalarMap;
+use crate::octree::Octree;
+use crate::cache::GeometryCache;
 
 #[derive( Clone)]
 pub struct PointCloud {
@@ -10,64 +19,121 @@ pub struct PointCloud {
     pub xform: Mat4,
     pub size: f32, 
     pub color: egui::Color32,
-    pub points: Vec<Vec3>,
+    points: Vec<Vec3>, // private so every edit goes through a method that rebuilds the octree
+    pub colors: Vec<egui::Color32>, // optional color per point, used instead of color when there is one for every point
+    pub sizes: Vec<f32>, // optional radius per point, used instead of size when there is one for every point
+    pub point_budget: Option<usize>, // most points drawn per frame, picked by level of detail. None draws all of them
+    octree: GeometryCache<Octree>, // built on first use, and again after set_points or points_mut
     }
 impl PointCloud {
     pub fn new(name: Option<String>,xform: Mat4, size: f32, color: egui::Color32, points: Vec<Vec3>)->PointCloud{
         PointCloud {
-            name: name,
-            xform: xform,
-            size: size,
-            color: color,
-            points: points,
+            octree: GeometryCache::new(),
+            name,
+            xform,
+            size,
+            color,
+            points,
+            colors: Vec::new(),
+            sizes: Vec::new(),
+            point_budget: None,
             }
     }
+    pub fn with_colors(mut self, colors: Vec<egui::Color32>)->PointCloud{
+        self.colors = colors;
+        self
+    }
+    // colors the points by a scalar attribute, one value per point
+    pub fn with_scalars(mut self, values: &[f32], map: &ScalarMap)->PointCloud{
+        self.colors = map.colors(values);
+        self
+    }
+    pub fn with_sizes(mut self, sizes: Vec<f32>)->PointCloud{
+        self.sizes = sizes;
+        self
+    }
+    pub fn with_point_budget(mut self, budget: usize)->PointCloud{
+        self.point_budget = Some(budget);
+        self
+    }
+    // replaces the points and rebuilds the octree on its next use
+    pub fn set_points(&mut self, points: Vec<Vec3>){
+        self.points = points;
+        self.octree.invalidate();
+    }
+    // the points to move in place, the octree gets rebuilt on its next use
+    pub fn points_mut(&mut self) -> &mut [Vec3]{
+        self.octree.invalidate();
+        &mut self.points
+    }
+    pub fn points(&self) -> &[Vec3]{
+        &self.points
+    }
+    // octree over the points, for level of detail and spatial queries in the space of the points (without xform)
+    pub fn octree(&self) -> Arc<Octree>{
+        self.octree.get(|| Octree::new(&self.points))
+    }
+    // index of the point closest to p and its distance, p in the space of the points
+    pub fn nearest_point(&self, p: Vec3) -> Option<(usize, f32)>{
+        self.octree().nearest(&self.points, p)
+    }
+    // largest radius of all points
+    fn max_size(&self) -> f32{
+        self.sizes.iter().fold(self.size, |max, s| max.max(*s))
+    }
+    fn size(&self, i: usize) -> f32{
+        if self.sizes.len() == self.points.len() { self.sizes[i] } else { self.size }
+    }
 }
 
 impl Shape3d for PointCloud{
-    fn draw(&self,ui: &mut egui::Ui, cam: &dyn Camera, painter: &egui::Painter, response: &egui::Response) {
-        let viewport_size = response.rect.size(); //egui::Vec2::new(ui.available_width()-50.0, ui.available_height()-50.0)
-        // Get the relative position of our "canvas"
-let to_screen = egui::emath::RectTransform::from_to(
-    egui::Rect::from_min_size(egui::Pos2::ZERO, response.rect.size()),
-    response.rect,
-);
-        
- 
-    let pivot = self.xform.transform_point3(Vec3::ZERO);
-    let pivot_projected = cam.project_point( pivot );
-    let pivot_screen = egui::Pos2::new( pivot_projected.x * viewport_size.x, pivot_projected.y * viewport_size.y)  + viewport_size/2.0;
-    
-    let radius_p = self.xform.transform_point3(Vec3::ZERO)+self.size * cam.get_xform().x_axis.truncate();
-    let radius_p_projected = cam.project_point( radius_p );
-    let radius_p_screen = egui::Pos2::new( radius_p_projected.x * viewport_size.x, radius_p_projected.y * viewport_size.y)  + viewport_size/2.0;
-    let circle_screen_size = (pivot_screen-radius_p_screen).length();
-    
-    let cam_pos = cam.get_center();
-    let attenuate = (1.0 - (cam_pos-pivot).length() / cam.get_far() ).clamp(0.0, 1.0);
-    let fill_alpha = 0.5 * attenuate;
-    let color = egui::Color32::from_rgba_unmultiplied(self.color.r(), self.color.g(), self.color.b(), ( (self.color.a() as f32 / 256.0 ) * fill_alpha*255.0 ) as u8 );
-        
-    // Paint the points
-    for p in &self.points{
-        let p_x = self.xform.transform_point3(*p);
-        let p_projected = cam.project_point( p_x );
-        let p_screen = egui::Pos2::new( p_projected.x * viewport_size.x, p_projected.y * viewport_size.y)  + viewport_size/2.0;
-        painter.add(egui::Shape::Circle(egui::epaint::CircleShape{
-            center: to_screen.transform_pos(p_screen),
-            radius: circle_screen_size,
-            fill:  color,   
-            stroke:  egui::Stroke::NONE 
-        }));
-    }
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let unit_screen_size = ctx.screen_radius(pivot, 1.0); // the whole cloud is sized like its pivot
+        let color = ctx.faded(self.color, pivot, 0.5);
+        let per_point_colors = self.colors.len() == self.points.len();
 
-    match &self.name{
-        Some(n) => {
-            let text_pos = pivot_screen;
-            painter.text(text_pos, egui::Align2::CENTER_CENTER, n.clone(), egui::FontId::monospace(16.0), color);
+        // Paint the points, with a budget only the level of detail the view needs
+        let indices: Vec<usize> = match self.point_budget {
+            Some(budget) => {
+                let min_spacing = (unit_screen_size * self.size * 2.0).max(1.0);
+                self.octree().lod(budget, min_spacing, |bounds| {
+                    let bounds = bounds.transformed(self.xform);
+                    if !ctx.is_visible(&bounds) { return None; }
+                    let radius = ctx.screen_radius(bounds.center(), bounds.size().length() / 2.0);
+                    Some(if radius > 0.0 { radius * 2.0 } else { f32::INFINITY }) // the camera is inside, refine it
+                })
             },
-        None => {}
+            _ => (0..self.points.len()).collect(),
+        };
+        let projected = ctx.project_points(&indices, |i| self.xform.transform_point3(self.points[*i]));
+        for (i, screen) in indices.into_iter().zip(projected) {
+            let Some((center, depth)) = screen else { continue; };
+            let fill = if per_point_colors { ctx.faded(self.colors[i], pivot, 0.5) } else { color };
+            ctx.add(depth, egui::epaint::CircleShape { center, radius: unit_screen_size * self.size(i), fill, stroke: egui::Stroke::NONE });
+        }
+
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), color);
+        }
+    }
+    
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
     }
-        
+    fn bounds(&self) -> Option<Aabb>{
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let points = self.octree().nodes.first()?.bounds.transformed(self.xform);
+        Some(points.expanded(self.max_size()).union(&Aabb::around(pivot, 0.0))) // keep the label at the pivot
     }
-}
\ No newline at end of file
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let unit_radius = clipping::screen_radius(&cam.view_projection(), query.rect, pivot, cam.get_right(), 1.0);
+        let cam_pos = cam.get_center();
+        // only test the points the octree finds around the pointer
+        let region = picking::pick_region(cam, query, (unit_radius * self.max_size()).max(query.tolerance)) * self.xform;
+        self.octree().frustum(&self.points, &region).into_iter()
+            .filter_map(|i| picking::pick_point(cam, query, self.xform.transform_point3(self.points[i]), unit_radius * self.size(i)))
+            .min_by(|a, b| (*a - cam_pos).length().total_cmp(&(*b - cam_pos).length()))
+    }
+}
@@ -1,76 +1,82 @@
-use egui::{self, Response};
-use glam::{Vec3, Mat4, Quat};
+use egui;
+use glam::{Vec3, Mat4};
 
 use super::Shape3d;
+use crate::bounds::Aabb;
 use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::clipping;
+use crate::picking::{self, PickQuery};
+use crate::lighting::{Light, Falloff};
 
 #[derive( Clone)]
 pub struct PointLight {
     pub name: Option<String>,
     pub xform: Mat4,
     pub size: f32,
-    pub color: egui::Color32,
+    pub color: egui::Color32, // color of the light it casts, the icon is always drawn yellow
+    pub intensity: f32,
+    pub falloff: Falloff,
     }
 impl PointLight {
     pub fn new( name: Option<String>, xform: Mat4, size: f32, color: egui::Color32)->PointLight{
         PointLight {
-            name: name,
-            xform: xform,
-            size: size,
-            color: color,
+            name,
+            xform,
+            size,
+            color,
+            intensity: 1.0,
+            falloff: Falloff::None,
             }
     }
+    pub fn with_intensity(mut self, intensity: f32)->Self{
+        self.intensity = intensity;
+        self
+    }
+    pub fn with_falloff(mut self, falloff: Falloff)->Self{
+        self.falloff = falloff;
+        self
+    }
+}
+
+// stroke every light icon is drawn with, thinner the further the light is from the camera
+pub(crate) fn icon_stroke(ctx: &DrawContext<'_>, pivot: Vec3) -> egui::Stroke {
+    egui::Stroke::new(2.0 * (ctx.fade(pivot)+0.1), egui::Color32::from_rgba_unmultiplied(255, 255, 160, 255 ))
 }
 
 impl Shape3d for PointLight{
-    fn draw(&self,ui: &mut egui::Ui, cam: &dyn Camera, painter: &egui::Painter, response: &egui::Response) {
-        let viewport_size = response.rect.size(); //egui::Vec2::new(ui.available_width()-50.0, ui.available_height()-50.0)
-        // Get the relative position of our "canvas"
-let to_screen = egui::emath::RectTransform::from_to(
-    egui::Rect::from_min_size(egui::Pos2::ZERO, response.rect.size()),
-    response.rect,
-);
-        
- 
-    let pivot = self.xform.transform_point3(Vec3::ZERO);
-    let pivot_projected = cam.project_point( pivot );
-    let pivot_screen = egui::Pos2::new( pivot_projected.x * viewport_size.x, pivot_projected.y * viewport_size.y)  + viewport_size/2.0;
-    
-    let radius_p = self.xform.transform_point3(Vec3::ZERO)+self.size * cam.get_xform().x_axis.truncate();
-    let radius_p_projected = cam.project_point( radius_p );
-    let radius_p_screen = egui::Pos2::new( radius_p_projected.x * viewport_size.x, radius_p_projected.y * viewport_size.y)  + viewport_size/2.0;
-    let circle_screen_size = (pivot_screen-radius_p_screen).length();
-    
-    let cam_pos = cam.get_center();
-    let mut stroke_width = 2.0;
-    let attenuate = (1.0 - (cam_pos-pivot).length() / cam.get_far() ).clamp(0.0, 1.0);
-    stroke_width *= attenuate+0.1; //attenuate by distance from camera
-    let fill_alpha = 0.7 * attenuate;
-    let stroke_color = egui::Color32::from_rgba_unmultiplied(255, 255, 160, 255 );
-    
-    //paint the circle
-    painter.add(egui::Shape::Circle(egui::epaint::CircleShape{
-        center: to_screen.transform_pos(pivot_screen),
-        radius: circle_screen_size,
-        fill:  egui::Color32::from_rgba_unmultiplied(255, 255, 0, (fill_alpha*255.0) as u8 ),   
-        stroke:  egui::Stroke::new(stroke_width, stroke_color) 
-        }));
-        
-    // Paint the rays
-    for r in 0..17{
-        let u =  r as f32 / 17.0 * std::f32::consts::PI *2.0;
-        let dir = egui::Vec2::new(u.sin(), u.cos());
-        let start_p = pivot_screen+(dir*circle_screen_size*1.1);
-        let end_p = pivot_screen+(dir*circle_screen_size*1.5);
-        // Paint the line!
-        painter.add(egui::Shape::LineSegment {
-            points: [to_screen.transform_pos(start_p), to_screen.transform_pos(end_p)],
-            stroke: egui::Stroke {
-                width: stroke_width,
-                color: stroke_color,
-            },
-        });
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let Some((pivot_screen, depth)) = ctx.world_to_screen(pivot) else { return; };
+        let circle_screen_size = ctx.screen_radius(pivot, self.size);
+        let fill_alpha = 0.7 * ctx.fade(pivot);
+        let stroke = icon_stroke(ctx, pivot);
+
+        //paint the circle
+        ctx.circle(pivot, circle_screen_size, egui::Color32::from_rgba_unmultiplied(255, 255, 0, (fill_alpha*255.0) as u8 ), stroke);
+
+        // Paint the rays
+        for r in 0..17{
+            let u =  r as f32 / 17.0 * std::f32::consts::PI *2.0;
+            let dir = egui::Vec2::new(u.sin(), u.cos());
+            let start_p = pivot_screen+(dir*circle_screen_size*1.1);
+            let end_p = pivot_screen+(dir*circle_screen_size*1.5);
+            ctx.add(depth, egui::Shape::LineSegment { points: [start_p, end_p], stroke });
+        }
     }
     
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn bounds(&self) -> Option<Aabb>{
+        Some(Aabb::around(self.xform.transform_point3(Vec3::ZERO), self.size * 1.5)) // include the rays
     }
-}
\ No newline at end of file
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let radius = clipping::screen_radius(&cam.view_projection(), query.rect, pivot, cam.get_right(), self.size) * 1.5; // include the rays
+        picking::pick_point(cam, query, pivot, radius)
+    }
+    fn light(&self) -> Option<Light>{
+        Some(Light::new(self.xform.transform_point3(Vec3::ZERO), self.color, self.intensity, self.falloff))
+    }
+}
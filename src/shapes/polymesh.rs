@@ -1,8 +1,16 @@
-use egui::{self, Response};
-use glam::{Vec3, Mat4, Quat};
+use std::sync::Arc;
 
-use super::Shape3d;
+use egui;
+use glam::{Vec3, Vec4, Mat4};
+
+use super::{Shape3d, DisplayMode};
+use crate::bounds::Aabb;
 use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+use crate::colormap::ScalarMap;
+use crate::bvh::Bvh;
+use crate::cache::GeometryCache;
 
 #[derive( Clone)]
 pub struct Polymesh {
@@ -10,89 +18,211 @@ pub struct Polymesh {
     pub xform: Mat4,
     pub line_width: f32,
     pub color: egui::Color32,
-    pub points: Vec<Vec3>,
-    pub indices: Vec<usize>,
-    pub counts: Vec<usize>, 
+    points: Vec<Vec3>, // private so every edit goes through a method that rebuilds the face bvh
+    indices: Vec<usize>,
+    counts: Vec<usize>,
+    pub normals: Vec<Vec3>, // optional normal per face vertex, in the order of indices, shaded faces are lit with their average
+    pub colors: Vec<egui::Color32>, // optional color per point, faces get filled with the average of their points
+    pub face_colors: Vec<egui::Color32>, // optional color per face, wins over the point colors
+    pub display: DisplayMode,
+    bvh: GeometryCache<FaceBvh>, // built on first use, and again after set_geometry or points_mut
     }
 impl Polymesh {
     pub fn new( name: Option<String>,xform: Mat4, line_width: f32, color: egui::Color32, points: Vec<Vec3>, indices: Vec<usize>, counts: Vec<usize>)->Polymesh{
         Polymesh {
-            name: name,
-            xform: xform,
-            line_width: line_width,
-            color: color,
-            points: points,
-            indices: indices,
-            counts: counts,
+            name,
+            xform,
+            line_width,
+            color,
+            points,
+            indices,
+            counts,
+            normals: Vec::new(),
+            colors: Vec::new(),
+            face_colors: Vec::new(),
+            display: DisplayMode::Wireframe,
+            bvh: GeometryCache::new(),
             }
     }
+    pub fn with_normals(mut self, normals: Vec<Vec3>)->Self{
+        self.normals = normals;
+        self
+    }
+    pub fn with_colors(mut self, colors: Vec<egui::Color32>)->Self{
+        self.colors = colors;
+        self
+    }
+    pub fn with_face_colors(mut self, face_colors: Vec<egui::Color32>)->Self{
+        self.face_colors = face_colors;
+        self
+    }
+    // colors the points by a scalar attribute, one value per point
+    pub fn with_point_scalars(mut self, values: &[f32], map: &ScalarMap)->Self{
+        self.colors = map.colors(values);
+        self
+    }
+    // colors the faces by a scalar attribute, one value per face
+    pub fn with_face_scalars(mut self, values: &[f32], map: &ScalarMap)->Self{
+        self.face_colors = map.colors(values);
+        self
+    }
+    pub fn with_display(mut self, display: DisplayMode)->Self{
+        self.display = display;
+        self
+    }
+
+    // replaces points, indices and counts and rebuilds the face bvh on its next use
+    pub fn set_geometry(&mut self, points: Vec<Vec3>, indices: Vec<usize>, counts: Vec<usize>){
+        self.points = points;
+        self.indices = indices;
+        self.counts = counts;
+        self.bvh.invalidate();
+    }
+    // the points to move in place, the face bvh gets rebuilt on its next use
+    pub fn points_mut(&mut self) -> &mut [Vec3]{
+        self.bvh.invalidate();
+        &mut self.points
+    }
+    pub fn points(&self) -> &[Vec3]{
+        &self.points
+    }
+    // indices into the points, face after face
+    pub fn indices(&self) -> &[usize]{
+        &self.indices
+    }
+    // number of points of each face
+    pub fn counts(&self) -> &[usize]{
+        &self.counts
+    }
+    // bvh over the faces, for culling and spatial queries in the space of the points (without xform)
+    pub fn face_bvh(&self) -> Arc<FaceBvh>{
+        self.bvh.get(|| FaceBvh::new(self))
+    }
+    // closest face hit by the ray and the ray parameter of the hit, the ray in the space of the points
+    pub fn ray_faces(&self, origin: Vec3, dir: Vec3) -> Option<(usize, f32)>{
+        let index = self.face_bvh();
+        index.bvh.ray(origin, dir, |face_i| {
+            let face = index.face(&self.indices, face_i);
+            // fan triangulation of the face
+            (1..face.len().saturating_sub(1))
+                .filter_map(|i| picking::ray_triangle(origin, dir, self.points[face[0]], self.points[face[i]], self.points[face[i+1]]))
+                .min_by(f32::total_cmp)
+        })
+    }
+    // faces in the view frustum of `view_projection`, which has to include xform, in the order they were added
+    pub fn faces_in_frustum(&self, view_projection: &Mat4) -> Vec<usize>{
+        let mut faces = self.face_bvh().bvh.frustum(view_projection);
+        faces.sort_unstable();
+        faces
+    }
+    // face closest to p with the closest point on it, p in the space of the points
+    pub fn nearest_face(&self, p: Vec3) -> Option<(usize, Vec3)>{
+        let index = self.face_bvh();
+        let closest_on = |face_i: usize| {
+            let face = index.face(&self.indices, face_i);
+            (1..face.len().saturating_sub(1))
+                .map(|i| picking::closest_point_on_triangle(p, self.points[face[0]], self.points[face[i]], self.points[face[i+1]]))
+                .min_by(|a, b| (*a - p).length_squared().total_cmp(&(*b - p).length_squared()))
+        };
+        let (face_i, _) = index.bvh.nearest(p, |face_i| closest_on(face_i).map_or(f32::INFINITY, |c| (c - p).length_squared()))?;
+        closest_on(face_i).map(|c| (face_i, c))
+    }
+}
+
+// faces of a polymesh in a bvh, together with where each face starts in the indices
+pub struct FaceBvh {
+    pub bvh: Bvh,
+    pub face_starts: Vec<usize>,
+}
+impl FaceBvh {
+    fn new(mesh: &Polymesh)->Self{
+        let mut face_starts = Vec::with_capacity(mesh.counts.len());
+        let mut current_index = 0;
+        for c in mesh.counts.iter() {
+            face_starts.push(current_index);
+            current_index += *c;
+        }
+        let face_bounds: Vec<Aabb> = face_starts.iter().zip(mesh.counts.iter())
+            .map(|(start, c)| Aabb::from_points(mesh.indices[*start..start+c].iter().map(|i| mesh.points[*i])).unwrap_or(Aabb::around(Vec3::ZERO, 0.0)))
+            .collect();
+        FaceBvh {
+            bvh: Bvh::new(&face_bounds),
+            face_starts,
+        }
+    }
+    // point indices of a face
+    pub fn face<'a>(&self, indices: &'a [usize], face_i: usize) -> &'a [usize]{
+        let start = self.face_starts[face_i];
+        let end = self.face_starts.get(face_i + 1).copied().unwrap_or(indices.len());
+        &indices[start..end]
+    }
 }
 
 impl Shape3d for Polymesh{
-    fn draw(&self,ui: &mut egui::Ui, cam: &dyn Camera, painter: &egui::Painter, response: &egui::Response) {
-        let viewport_size = response.rect.size(); //egui::Vec2::new(ui.available_width()-50.0, ui.available_height()-50.0)
-        // Get the relative position of our "canvas"
-let to_screen = egui::emath::RectTransform::from_to(
-    egui::Rect::from_min_size(egui::Pos2::ZERO, response.rect.size()),
-    response.rect,
-);
-        
-    let pivot = self.xform.transform_point3(Vec3::ZERO);
-    let pivot_projected = cam.project_point( pivot );
-    let pivot_screen = egui::Pos2::new( pivot_projected.x * viewport_size.x, pivot_projected.y * viewport_size.y)  + viewport_size/2.0;
-    
-    let cam_pos = cam.get_center();
-    let attenuate = (1.0 - (cam_pos-pivot).length() / cam.get_far() ).clamp(0.0, 1.0);
-    let fill_alpha = 0.5 * attenuate;
-    let color = egui::Color32::from_rgba_unmultiplied(self.color.r(), self.color.g(), self.color.b(), ( (self.color.a() as f32 / 256.0 ) * fill_alpha*255.0 ) as u8 );
-    let stroke = egui::Stroke {
-            width: self.line_width,
-            color: color,
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let color = ctx.faded(self.color, pivot, 0.5);
+        let stroke = egui::Stroke::new(self.line_width, color);
+
+        // Only the faces in view, with their points transformed once up front. When most of the mesh is culled
+        // only the points those faces use get transformed, `slots` maps them to their place in `transformed`
+        let index = self.face_bvh();
+        let faces = self.faces_in_frustum(&(*ctx.view_projection() * self.xform));
+        let mut slots: Vec<usize> = Vec::new();
+        let transformed = if faces.len() * 2 < self.counts.len() {
+            slots = vec![usize::MAX; self.points.len()];
+            let mut used: Vec<Vec3> = Vec::new();
+            for vtx_i in faces.iter().flat_map(|face_i| index.face(&self.indices, *face_i)) {
+                if slots[*vtx_i] == usize::MAX {
+                    slots[*vtx_i] = used.len();
+                    used.push(self.points[*vtx_i]);
+                }
+            }
+            ctx.transform_points(self.xform, &used)
+        } else {
+            ctx.transform_points(self.xform, &self.points)
         };
-        
-    let mut current_index = 0;
-    // for each face
-    for c in self.counts.iter(){
-        let mut path_pts = Vec::with_capacity(*c);
-        // for each vtx of the face
-        for rel_i in current_index..(current_index+*c){
-            let vtx_i = self.indices[rel_i];
-            let vtx_P = self.points[vtx_i];
-            let p_xformed = self.xform.transform_point3(vtx_P);
-            let p_projected = cam.project_point( p_xformed );
-            let p_screen = egui::Pos2::new( p_projected.x * viewport_size.x, p_projected.y * viewport_size.y)  + viewport_size/2.0;
-            path_pts.push(p_screen);
+        let slot = |vtx_i: usize| if slots.is_empty() { vtx_i } else { slots[vtx_i] };
+        let has_normals = self.normals.len() == self.indices.len();
+        let normal_xform = self.xform.inverse().transpose();
+        for face_i in faces {
+            let face = index.face(&self.indices, face_i);
+            let face_pts: Vec<(Vec3, Vec4)> = face.iter().map(|vtx_i| transformed[slot(*vtx_i)]).collect();
+            let fill = if self.face_colors.len() == self.counts.len() {
+                self.face_colors[face_i]
+            } else if self.colors.len() == self.points.len() && !face.is_empty() {
+                // Color32 is premultiplied, so is the average
+                let sum = face.iter().fold([0_u32; 4], |sum, vtx_i| {
+                    let c = self.colors[*vtx_i];
+                    [sum[0] + c.r() as u32, sum[1] + c.g() as u32, sum[2] + c.b() as u32, sum[3] + c.a() as u32]
+                });
+                let avg = sum.map(|channel| (channel / face.len() as u32) as u8);
+                egui::Color32::from_rgba_premultiplied(avg[0], avg[1], avg[2], avg[3])
+            } else {
+                self.color
+            };
+            let start = index.face_starts[face_i];
+            let normal = has_normals.then(|| normal_xform.transform_vector3(self.normals[start..start + face.len()].iter().sum()));
+            ctx.face_clipped(&face_pts, normal, fill, stroke, self.display);
+        }
+
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), color);
         }
-        //calculate the face normal
-        let C_P = self.points[self.indices[current_index + *c -1]];
-        let B_P = self.points[self.indices[current_index + *c -2]];
-        let A_P = self.points[self.indices[current_index + *c -3]];
-        let dir_BC = (C_P - B_P).normalize();
-        let dir_BA = (A_P - B_P).normalize();
-        let N = dir_BA.cross(dir_BC);
-        
-        let cam_pos = cam.get_center();
-        let V = ( (C_P+A_P+B_P)/3.0 - cam_pos ).normalize();
-        let N_dot_V = - N.dot(V);
-        
-    
-        // draw the face wire
-        let mut stroke_local = stroke.clone();
-        stroke_local.color = egui::Color32::from_rgba_unmultiplied( stroke_local.color.r(),stroke_local.color.g(),stroke_local.color.b(), (N_dot_V.max(0.0) * 255.0) as u8 );
-        painter.add(
-            egui::epaint::PathShape::line(path_pts, stroke_local)
-        );
-        
-        current_index += *c;
     }
     
-    match &self.name{
-        Some(n) => {
-            let text_pos = pivot_screen;
-            painter.text(text_pos, egui::Align2::CENTER_CENTER, n.clone(), egui::FontId::monospace(16.0), color);
-            },
-        None => {}
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
     }
-    
+    fn bounds(&self) -> Option<Aabb>{
+        Some(self.face_bvh().bvh.bounds()?.transformed(self.xform))
     }
-}
\ No newline at end of file
+    fn pick(&self, _cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        // intersect in object space, the ray parameter stays the same as the direction is not normalized again
+        let inv = self.xform.inverse();
+        let origin = inv.transform_point3(query.ray_origin);
+        let dir = inv.transform_vector3(query.ray_dir);
+        let (_, t) = self.ray_faces(origin, dir)?;
+        Some(query.ray_origin + query.ray_dir * t)
+    }
+}
@@ -0,0 +1,88 @@
+use egui;
+use glam::{Vec3, Mat4};
+
+use super::{Shape3d, DisplayMode};
+use crate::bounds::Aabb;
+use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+
+// uv sphere centered in the origin of xform
+#[derive(Clone)]
+pub struct Sphere {
+    pub name: Option<String>,
+    pub xform: Mat4,
+    pub radius: f32,
+    pub line_width: f32,
+    pub color: egui::Color32,
+    pub display: DisplayMode,
+    pub rings: usize, // subdivisions from pole to pole
+    pub segments: usize, // subdivisions around the y axis
+}
+impl Sphere {
+    pub fn new(name: Option<String>, xform: Mat4, radius: f32, line_width: f32, color: egui::Color32)->Sphere{
+        Sphere {
+            name,
+            xform,
+            radius,
+            line_width,
+            color,
+            display: DisplayMode::Wireframe,
+            rings: 12,
+            segments: 24,
+        }
+    }
+    pub fn with_display(mut self, display: DisplayMode)->Self{
+        self.display = display;
+        self
+    }
+    pub fn with_subdivisions(mut self, rings: usize, segments: usize)->Self{
+        self.rings = rings.max(2);
+        self.segments = segments.max(3);
+        self
+    }
+
+    fn point(&self, ring: usize, segment: usize) -> Vec3{
+        let theta = ring as f32 / self.rings as f32 * std::f32::consts::PI;
+        let phi = segment as f32 / self.segments as f32 * std::f32::consts::TAU;
+        self.xform.transform_point3(Vec3::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin()) * self.radius)
+    }
+}
+
+impl Shape3d for Sphere{
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let color = ctx.faded(self.color, pivot, 0.5);
+        let stroke = egui::Stroke::new(self.line_width, color);
+
+        for ring in 0..self.rings {
+            for segment in 0..self.segments {
+                // wound clockwise seen from the outside, the quads touching the poles collapse into triangles
+                let face_pts = [
+                    self.point(ring, segment),
+                    self.point(ring + 1, segment),
+                    self.point(ring + 1, segment + 1),
+                    self.point(ring, segment + 1),
+                ];
+                ctx.face(&face_pts, None, self.color, stroke, self.display);
+            }
+        }
+
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), color);
+        }
+    }
+
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn bounds(&self) -> Option<Aabb>{
+        Some(Aabb::around(Vec3::ZERO, self.radius).transformed(self.xform))
+    }
+    fn pick(&self, _cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        // intersect in object space so scaled spheres work too
+        let inv = self.xform.inverse();
+        let t = picking::ray_sphere(inv.transform_point3(query.ray_origin), inv.transform_vector3(query.ray_dir), Vec3::ZERO, self.radius)?;
+        Some(query.ray_origin + query.ray_dir * t)
+    }
+}
@@ -0,0 +1,86 @@
+use egui;
+use glam::{Vec3, Mat4};
+
+use super::Shape3d;
+use super::point_light::icon_stroke;
+use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+use crate::lighting::{Light, Falloff};
+
+// light cone with its apex in the origin of xform, shining down the -z axis
+#[derive(Clone)]
+pub struct SpotLight {
+    pub name: Option<String>,
+    pub xform: Mat4,
+    pub size: f32, // length of the drawn cone
+    pub color: egui::Color32, // color of the light it casts, the icon is drawn like the point light one
+    pub inner_angle: f32, // half angle in degrees where the light starts to fall off
+    pub outer_angle: f32, // half angle in degrees where the light ends
+    pub intensity: f32,
+    pub falloff: Falloff,
+}
+impl SpotLight {
+    pub fn new(name: Option<String>, xform: Mat4, size: f32, color: egui::Color32, inner_angle: f32, outer_angle: f32)->SpotLight{
+        SpotLight {
+            name,
+            xform,
+            size,
+            color,
+            inner_angle,
+            outer_angle,
+            intensity: 1.0,
+            falloff: Falloff::None,
+        }
+    }
+    pub fn with_intensity(mut self, intensity: f32)->Self{
+        self.intensity = intensity;
+        self
+    }
+    pub fn with_falloff(mut self, falloff: Falloff)->Self{
+        self.falloff = falloff;
+        self
+    }
+
+    // outer cone with four lines from the apex, the inner cone only as a circle
+    fn polylines(&self) -> (Vec<Vec<Vec3>>, Vec<Vec3>){
+        let outer_radius = self.size * self.outer_angle.clamp(0.0, 89.0).to_radians().tan();
+        let inner_radius = self.size * self.inner_angle.clamp(0.0, 89.0).to_radians().tan();
+        let apex = self.xform.transform_point3(Vec3::ZERO);
+        let mut lines = vec![super::circle_points(self.xform, outer_radius, -self.size, 32)];
+        for dir in [Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y] {
+            lines.push(vec![apex, self.xform.transform_point3(dir * outer_radius - Vec3::Z * self.size)]);
+        }
+        (lines, super::circle_points(self.xform, inner_radius, -self.size, 32))
+    }
+}
+
+impl Shape3d for SpotLight{
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let stroke = icon_stroke(ctx, pivot);
+        let inner_stroke = egui::Stroke::new(stroke.width * 0.5, stroke.color.gamma_multiply(0.6));
+        let (outer, inner) = self.polylines();
+        for line in outer {
+            ctx.polyline(&line, stroke);
+        }
+        ctx.polyline(&inner, inner_stroke);
+        if let Some(n) = &self.name {
+            ctx.label(pivot, n.clone(), stroke.color);
+        }
+    }
+
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        let (outer, _) = self.polylines();
+        outer.iter()
+            .filter_map(|line| picking::pick_polyline(cam, query, line))
+            .min_by(|a, b| cam.dist_to_point(*a).total_cmp(&cam.dist_to_point(*b)))
+    }
+    fn light(&self) -> Option<Light>{
+        let (position, direction) = (self.xform.transform_point3(Vec3::ZERO), self.xform.transform_vector3(-Vec3::Z));
+        Some(Light::spot(position, direction, self.color, self.intensity, self.falloff, self.inner_angle, self.outer_angle))
+    }
+}
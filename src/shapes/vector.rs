@@ -1,10 +1,14 @@
-use egui::{self, Response};
-use glam::{Vec3, Mat4, Quat};
+use egui;
+use glam::{Vec3, Mat4};
 
 use super::Shape3d;
+use crate::bounds::Aabb;
 use crate::camera::Camera;
+use crate::draw_context::DrawContext;
+use crate::picking::{self, PickQuery};
+use crate::colormap::ScalarMap;
 
-const phi: f32 = std::f32::consts::PI;
+const PHI: f32 = std::f32::consts::PI;
 
 #[derive(Clone)]
 pub struct Vector { 
@@ -17,70 +21,60 @@ pub struct Vector {
 impl Vector {
     pub fn new(name: Option<String>,xform: Mat4, size: f32, color: egui::Color32, dir: Vec3)->Vector{
         Vector {
-            name: name,
-            xform: xform,
-            size: size, 
-            color: color,
-            dir:dir,
+            name,
+            xform,
+            size, 
+            color,
+            dir,
             }
     }
+    // colors the vector by a value, e.g. its magnitude in a vector field. The map needs a fixed range
+    // to give all vectors of the field the same scale, see ScalarMap::resolve_range
+    pub fn with_scalar(mut self, value: f32, map: &ScalarMap)->Vector{
+        self.color = map.color(value);
+        self
+    }
 }
 
 impl Shape3d for Vector{
-    fn draw(&self,ui: &mut egui::Ui, cam: &dyn Camera, painter: &egui::Painter, response: &egui::Response) {
-        let viewport_size = response.rect.size(); //egui::Vec2::new(ui.available_width()-50.0, ui.available_height()-50.0)
-        // Get the relative position of our "canvas"
-let to_screen = egui::emath::RectTransform::from_to(
-    egui::Rect::from_min_size(egui::Pos2::ZERO, response.rect.size()),
-    response.rect,
-);
-        
- 
-    let pivot = self.xform.transform_point3(Vec3::ZERO);
-    let pivot_projected = cam.project_point( pivot );
-    let pivot_screen = egui::Pos2::new( pivot_projected.x * viewport_size.x, pivot_projected.y * viewport_size.y)  + viewport_size/2.0;
-    
-    let end = self.xform.transform_point3(self.dir * self.size);
-    let end_projected = cam.project_point( end );
-    let end_screen = egui::Pos2::new( end_projected.x * viewport_size.x, end_projected.y * viewport_size.y)  + viewport_size/2.0;
-    
-    let cam_pos = cam.get_center();
-    let mut stroke_width = 4.0;
-    let attenuate = (1.0 - (cam_pos-pivot).length() / cam.get_far() ).clamp(0.0, 1.0);
-    stroke_width *= attenuate+0.1; //attenuate by distance from camera
-    let fill_alpha = 0.5 * attenuate;
-    
-    //paint the main line
-    painter.add(egui::Shape::LineSegment {
-        points: [to_screen.transform_pos(pivot_screen), to_screen.transform_pos(end_screen)],
-        stroke: egui::Stroke {
-            width: stroke_width,
-            color: self.color,
-        },
-    });
-    let arrow_size = 0.07;
-    // Paint the arrow tip
-    let mut tip_pts: Vec<egui::Pos2> = Vec::with_capacity(63);
-    for r in 0..600{
-        let u =  r as f32 / 600.0;
-        let cu = u * phi * 80.0;
-        let a_width = arrow_size * (1.0-u);
-        let x_axis = self.dir.cross(Vec3::new(phi, phi, phi)).normalize();
+    fn draw(&self, ctx: &mut DrawContext<'_>) {
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let end = self.xform.transform_point3(self.dir * self.size);
+        let stroke = egui::Stroke::new(4.0 * (ctx.fade(pivot)+0.1), self.color); //attenuate by distance from camera
+
+        //paint the main line
+        ctx.segment(pivot, end, stroke);
+
+        // Paint the arrow tip
+        let Some((_, end_depth)) = ctx.world_to_screen(end) else { return; };
+        let arrow_size = 0.07;
+        let x_axis = self.dir.cross(Vec3::new(PHI, PHI, PHI)).normalize();
         let z_axis = x_axis.cross(self.dir).normalize();
-        let base_p = self.xform.transform_point3( 
-            self.dir * self.size * (1.0-arrow_size + arrow_size * u * 2.2) 
-            + x_axis * cu.sin() * a_width
-            + z_axis * cu.cos() * a_width
-        );
-        let base_p_projected = cam.project_point( base_p );
-        let base_p_screen = egui::Pos2::new( base_p_projected.x * viewport_size.x, base_p_projected.y * viewport_size.y)  + viewport_size/2.0;
-    tip_pts.push(to_screen.transform_pos(base_p_screen));
+        let tip_pts: Vec<egui::Pos2> = (0..600).filter_map(|r| {
+            let u =  r as f32 / 600.0;
+            let cu = u * PHI * 80.0;
+            let a_width = arrow_size * (1.0-u);
+            let base_p = self.xform.transform_point3( 
+                self.dir * self.size * (1.0-arrow_size + arrow_size * u * 2.2) 
+                + x_axis * cu.sin() * a_width
+                + z_axis * cu.cos() * a_width
+            );
+            ctx.world_to_screen(base_p).map(|(pos, _)| pos)
+        }).collect();
+        ctx.add(end_depth, egui::epaint::PathShape::line(tip_pts, stroke));
     }
     
-    painter.add(egui::Shape::Path(
-        egui::epaint::PathShape::line(tip_pts, egui::Stroke::new(stroke_width*1.0, self.color) ))
-    );
-        
-    
+    fn name(&self) -> Option<&str>{
+        self.name.as_deref()
+    }
+    fn bounds(&self) -> Option<Aabb>{
+        // the tip reaches a bit past the end and is 0.07 wide
+        let local = Aabb::from_points([Vec3::ZERO, self.dir * self.size * 1.1])?.expanded(0.07);
+        Some(local.transformed(self.xform))
     }
-}
\ No newline at end of file
+    fn pick(&self, cam: &dyn Camera, query: &PickQuery) -> Option<Vec3>{
+        let pivot = self.xform.transform_point3(Vec3::ZERO);
+        let end = self.xform.transform_point3(self.dir * self.size);
+        picking::pick_segment(cam, query, pivot, end)
+    }
+}
@@ -0,0 +1,269 @@
+use glam::{Vec3, Mat4};
+
+use crate::camera::Camera;
+use crate::clipping;
+use crate::picking;
+use crate::widgets::viewport3d::ViewportResponse;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GizmoMode {
+    Translate,
+    Rotate,
+    Scale, // always along the local axes
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GizmoSpace {
+    Local, // handles follow the rotation of the edited transform
+    World,
+}
+
+const AXIS_COLORS: [egui::Color32; 3] = [egui::Color32::RED, egui::Color32::GREEN, egui::Color32::BLUE];
+const ACTIVE_COLOR: egui::Color32 = egui::Color32::YELLOW;
+const RING_SEGMENTS: usize = 48;
+
+// state of a drag in progress, kept in egui memory
+#[derive(Copy, Clone, Debug)]
+struct DragState {
+    axis: usize,
+    start_xform: Mat4, // world space transform when the drag started
+    start_value: f32, // position along the axis or angle in the rotation plane when the drag started
+}
+
+// True if a drag on the viewport belongs to a gizmo drawn over it, because one is being dragged or a drag starts
+// on a handle the pointer was over last frame. The gizmo is drawn after the viewport has moved its camera,
+// so the viewport has to ask before the gizmo knows itself
+pub(crate) fn grabs_drag(response: &egui::Response) -> bool{
+    let id = response.id.with("gizmo");
+    response.ctx.data(|d| d.get_temp::<DragState>(id).is_some())
+        || (starts_drag(response) && response.ctx.data(|d| d.get_temp::<u64>(id.with("hovered")))
+            .is_some_and(|frame| frame + 1 >= response.ctx.frame_nr()))
+}
+// handles are only grabbed by a plain left drag, drags with modifiers are left to the navigation
+fn starts_drag(response: &egui::Response) -> bool{
+    response.drag_started_by(egui::PointerButton::Primary) && response.ctx.input(|i| i.modifiers.is_none())
+}
+
+// interactive translate / rotate / scale handles drawn over a viewport, edits the transform in place
+pub struct Gizmo<'a> {
+    xform: &'a mut Mat4,
+    parent_xform: Mat4,
+    mode: GizmoMode,
+    space: GizmoSpace,
+    size: f32,
+    snap: Option<f32>,
+}
+impl<'a> Gizmo<'a> {
+    pub fn new(xform: &'a mut Mat4)->Self{
+        Gizmo {
+            xform,
+            parent_xform: Mat4::IDENTITY,
+            mode: GizmoMode::Translate,
+            space: GizmoSpace::World,
+            size: 80.0,
+            snap: None,
+        }
+    }
+    // world transform of the parent when editing the local xform of a scene node
+    pub fn with_parent(mut self, parent_xform: Mat4)->Self{
+        self.parent_xform = parent_xform;
+        self
+    }
+    pub fn with_mode(mut self, mode: GizmoMode)->Self{
+        self.mode = mode;
+        self
+    }
+    pub fn with_space(mut self, space: GizmoSpace)->Self{
+        self.space = space;
+        self
+    }
+    // screen length of the handles in points
+    pub fn with_size(mut self, size: f32)->Self{
+        self.size = size;
+        self
+    }
+    // step in world units for translate, degrees for rotate and scale factor for scale
+    pub fn with_snap(mut self, snap: Option<f32>)->Self{
+        self.snap = snap;
+        self
+    }
+
+    // draws the handles and applies dragging them, returns true if the transform changed
+    pub fn interact(self, ui: &mut egui::Ui, viewport: &ViewportResponse) -> bool{
+        let response = &viewport.response;
+        let cam = viewport.camera.as_ref();
+        let rect = response.rect;
+        let id = response.id.with("gizmo");
+
+        let world = self.parent_xform * *self.xform;
+        let origin = world.transform_point3(Vec3::ZERO);
+        let Some(origin_screen) = to_screen(cam, rect, origin) else { return false; };
+        let points_per_unit = clipping::screen_radius(&cam.view_projection(), rect, origin, cam.get_right(), 1.0);
+        if points_per_unit <= 0.0 { return false; }
+        let length = self.size / points_per_unit; // keep the same size on screen at any distance
+        let axes = self.axes(world);
+
+        let mut state: Option<DragState> = ui.data(|d| d.get_temp(id));
+        let mut changed = false;
+
+        // pick the handle under the pointer when a drag starts
+        let hovered_axis = response.hover_pos().or(response.interact_pointer_pos())
+            .and_then(|pointer| self.hovered_axis(cam, rect, origin, &axes, length, pointer));
+        if hovered_axis.is_some() {
+            let frame = ui.ctx().frame_nr();
+            ui.data_mut(|d| d.insert_temp(id.with("hovered"), frame)); // frame the last handle was hovered in
+        }
+        if starts_drag(response) {
+            state = hovered_axis.and_then(|axis| {
+                let pointer = response.interact_pointer_pos()?;
+                let start_value = self.drag_value(cam, rect, origin, axes[axis], pointer)?;
+                Some(DragState { axis, start_xform: world, start_value })
+            });
+        }
+
+        if let Some(drag) = state {
+            if response.dragged() {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    let start_origin = drag.start_xform.transform_point3(Vec3::ZERO);
+                    let start_axes = self.axes(drag.start_xform);
+                    if let Some(value) = self.drag_value(cam, rect, start_origin, start_axes[drag.axis], pointer) {
+                        let new_world = self.apply(drag, start_origin, start_axes[drag.axis], value);
+                        let new_local = self.parent_xform.inverse() * new_world;
+                        changed = new_local != *self.xform;
+                        *self.xform = new_local;
+                    }
+                }
+            } else {
+                state = None;
+            }
+        }
+        match state {
+            Some(drag) => ui.data_mut(|d| d.insert_temp(id, drag)),
+            None => ui.data_mut(|d| d.remove::<DragState>(id)),
+        }
+
+        let active_axis = state.map(|s| s.axis).or(hovered_axis);
+        self.paint(&ui.painter_at(rect), cam, rect, origin, origin_screen, &axes, length, active_axis);
+        changed
+    }
+
+    fn axes(&self, world: Mat4) -> [Vec3; 3]{
+        match (self.mode, self.space) {
+            (GizmoMode::Scale, _) | (_, GizmoSpace::Local) => [
+                world.x_axis.truncate().normalize(),
+                world.y_axis.truncate().normalize(),
+                world.z_axis.truncate().normalize(),
+            ],
+            (_, GizmoSpace::World) => [Vec3::X, Vec3::Y, Vec3::Z],
+        }
+    }
+
+    // world space points of the ring around an axis
+    fn ring(origin: Vec3, axis: Vec3, radius: f32) -> Vec<Vec3>{
+        let u = axis.any_orthonormal_vector();
+        let v = axis.cross(u);
+        (0..=RING_SEGMENTS).map(|i| {
+            let a = i as f32 / RING_SEGMENTS as f32 * std::f32::consts::TAU;
+            origin + (u * a.cos() + v * a.sin()) * radius
+        }).collect()
+    }
+
+    fn hovered_axis(&self, cam: &dyn Camera, rect: egui::Rect, origin: Vec3, axes: &[Vec3; 3], length: f32, pointer: egui::Pos2) -> Option<usize>{
+        let tolerance = 8.0;
+        let dist_to_polyline = |pts: &[Vec3]| -> f32 {
+            let screen: Vec<egui::Pos2> = pts.iter().filter_map(|p| to_screen(cam, rect, *p)).collect();
+            screen.windows(2).map(|s| {
+                let ab = s[1] - s[0];
+                let t = if ab.length_sq() > 0.0 { ((pointer - s[0]).dot(ab) / ab.length_sq()).clamp(0.0, 1.0) } else { 0.0 };
+                (s[0] + ab * t - pointer).length()
+            }).fold(f32::INFINITY, f32::min)
+        };
+        (0..3).map(|axis| {
+            let dist = match self.mode {
+                GizmoMode::Rotate => dist_to_polyline(&Self::ring(origin, axes[axis], length)),
+                _ => dist_to_polyline(&[origin, origin + axes[axis] * length]),
+            };
+            (axis, dist)
+        })
+        .filter(|(_, dist)| *dist <= tolerance)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(axis, _)| axis)
+    }
+
+    // position along the axis line closest to the pointer ray, or the angle of the pointer in the plane of the ring
+    fn drag_value(&self, cam: &dyn Camera, rect: egui::Rect, origin: Vec3, axis: Vec3, pointer: egui::Pos2) -> Option<f32>{
+        let (ray_origin, ray_dir) = cam.screen_ray(pointer, rect);
+        match self.mode {
+            GizmoMode::Rotate => {
+                let t = picking::ray_plane(ray_origin, ray_dir, origin, axis)?;
+                let v = ray_origin + ray_dir * t - origin;
+                let u = axis.any_orthonormal_vector();
+                Some(v.dot(axis.cross(u)).atan2(v.dot(u)))
+            },
+            _ => {
+                // closest point between the axis line and the ray
+                let w0 = origin - ray_origin;
+                let b = axis.dot(ray_dir);
+                let denom = 1.0 - b * b;
+                if denom.abs() < 1e-6 { return None; } // looking straight down the axis
+                Some((b * ray_dir.dot(w0) - axis.dot(w0)) / denom)
+            },
+        }
+    }
+
+    fn snapped(&self, value: f32, step: f32) -> f32{
+        match self.snap {
+            Some(snap) if snap > 0.0 => (value / (snap * step)).round() * snap * step,
+            _ => value,
+        }
+    }
+
+    // new world transform for the current drag value
+    fn apply(&self, drag: DragState, origin: Vec3, axis: Vec3, value: f32) -> Mat4{
+        match self.mode {
+            GizmoMode::Translate => {
+                let offset = self.snapped(value - drag.start_value, 1.0);
+                Mat4::from_translation(axis * offset) * drag.start_xform
+            },
+            GizmoMode::Rotate => {
+                let angle = self.snapped(value - drag.start_value, 1.0_f32.to_radians());
+                Mat4::from_translation(origin) * Mat4::from_axis_angle(axis, angle) * Mat4::from_translation(-origin) * drag.start_xform
+            },
+            GizmoMode::Scale => {
+                if drag.start_value.abs() < 1e-6 { return drag.start_xform; }
+                let factor = 1.0 + self.snapped(value / drag.start_value - 1.0, 1.0);
+                let mut scale = Vec3::ONE;
+                scale[drag.axis] = factor;
+                drag.start_xform * Mat4::from_scale(scale)
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn paint(&self, painter: &egui::Painter, cam: &dyn Camera, rect: egui::Rect, origin: Vec3, origin_screen: egui::Pos2, axes: &[Vec3; 3], length: f32, active_axis: Option<usize>){
+        for (axis, dir) in axes.iter().enumerate() {
+            let color = if active_axis == Some(axis) { ACTIVE_COLOR } else { AXIS_COLORS[axis] };
+            let stroke = egui::Stroke::new(2.0, color);
+            match self.mode {
+                GizmoMode::Rotate => {
+                    let pts: Vec<egui::Pos2> = Self::ring(origin, *dir, length).iter()
+                        .filter_map(|p| to_screen(cam, rect, *p)).collect();
+                    painter.add(egui::epaint::PathShape::line(pts, stroke));
+                },
+                GizmoMode::Translate | GizmoMode::Scale => {
+                    let Some(tip) = to_screen(cam, rect, origin + *dir * length) else { continue; };
+                    painter.line_segment([origin_screen, tip], stroke);
+                    if self.mode == GizmoMode::Translate {
+                        painter.circle_filled(tip, 5.0, color);
+                    } else {
+                        painter.rect_filled(egui::Rect::from_center_size(tip, egui::Vec2::splat(8.0)), 0.0, color);
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn to_screen(cam: &dyn Camera, rect: egui::Rect, p: Vec3) -> Option<egui::Pos2>{
+    clipping::world_to_screen(&cam.view_projection(), rect, p).map(|(pos, _)| pos)
+}
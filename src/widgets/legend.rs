@@ -0,0 +1,70 @@
+use crate::colormap::ScalarMap;
+
+// horizontal color bar of a scalar map with the values at both ends
+pub struct Legend {
+    map: ScalarMap,
+    range: (f32, f32),
+    label: Option<String>,
+    width: f32,
+    height: f32,
+}
+impl Legend {
+    // range is the one the colors were made with, e.g. ScalarMap::resolve_range of the data
+    pub fn new(map: ScalarMap, range: (f32, f32))->Self{
+        Legend {
+            map,
+            range,
+            label: None,
+            width: 200.0,
+            height: 12.0,
+        }
+    }
+    pub fn with_label(mut self, label: impl Into<String>)->Self{
+        self.label = Some(label.into());
+        self
+    }
+    // size of the bar in points, the text goes below it
+    pub fn with_size(mut self, width: f32, height: f32)->Self{
+        self.width = width;
+        self.height = height;
+        self
+    }
+}
+
+impl egui::Widget for Legend {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let font = egui::TextStyle::Small.resolve(ui.style());
+        let text_height = ui.fonts(|f| f.row_height(&font));
+        let (rect, response) = ui.allocate_exact_size(egui::Vec2::new(self.width, self.height + text_height + 2.0), egui::Sense::hover());
+        if !ui.is_rect_visible(rect) { return response; }
+        let painter = ui.painter_at(rect);
+        let bar = egui::Rect::from_min_size(rect.min, egui::Vec2::new(self.width, self.height));
+
+        // the bar is linear in the normalized position, so log maps show the same gradient with other numbers at the ends
+        let steps = 64;
+        let mut mesh = egui::epaint::Mesh::default();
+        for i in 0..=steps {
+            let t = i as f32 / steps as f32;
+            let color = self.map.colormap.sample(if self.map.reversed { 1.0 - t } else { t });
+            let x = egui::lerp(bar.x_range(), t);
+            mesh.colored_vertex(egui::Pos2::new(x, bar.top()), color);
+            mesh.colored_vertex(egui::Pos2::new(x, bar.bottom()), color);
+            if i > 0 {
+                let v = 2 * i as u32;
+                mesh.add_triangle(v - 2, v - 1, v);
+                mesh.add_triangle(v - 1, v, v + 1);
+            }
+        }
+        painter.add(mesh);
+        painter.rect_stroke(bar, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+        let text_color = ui.visuals().text_color();
+        let text_y = bar.bottom() + 2.0;
+        painter.text(egui::Pos2::new(bar.left(), text_y), egui::Align2::LEFT_TOP, format!("{:.3}", self.range.0), font.clone(), text_color);
+        painter.text(egui::Pos2::new(bar.right(), text_y), egui::Align2::RIGHT_TOP, format!("{:.3}", self.range.1), font.clone(), text_color);
+        if let Some(label) = self.label {
+            painter.text(egui::Pos2::new(bar.center().x, text_y), egui::Align2::CENTER_TOP, label, font, text_color);
+        }
+        response
+    }
+}
@@ -1 +1,3 @@
-pub mod viewport3d;
\ No newline at end of file
+pub mod viewport3d;
+pub mod gizmo;
+pub mod legend;
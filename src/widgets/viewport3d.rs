@@ -1,59 +1,235 @@
+use std::collections::HashSet;
 use std::ops::Deref;
-use glam::Mat4;
 
-use crate::{Shape3d};
 use crate::camera::*;
+use crate::scene::Scene;
+use crate::selection::Selection;
+use crate::navigation::{CameraController, NavigationPreset};
+use crate::draw_list::DepthSort;
+use crate::render;
+use crate::draw_context::DrawStyle;
+use crate::picking::{Pick, PickQuery};
+use crate::widgets::gizmo;
 
-pub struct Viewport3d {
+enum Controller<'a> {
+    Owned(CameraController), // state is kept in egui memory under the viewport id
+    Borrowed(&'a mut CameraController), // state is kept by the caller
+}
+
+pub struct ViewportResponse {
+    pub response: egui::Response,
+    pub hovered: Option<Pick>, // shape under the pointer
+    pub clicked: Option<Pick>, // shape under the pointer if the viewport was clicked this frame
+    pub camera: Box<dyn Camera>, // the camera as it was drawn this frame, fitted to the viewport and moved by the controller
+    pub selection_changed: bool, // the viewport selection was changed by clicking or box selecting this frame
+}
+
+pub struct Viewport3d<'a> {
     width: usize,
     height: usize,
-    scene: Vec<Box<dyn Shape3d>>,
-    camera: Box<dyn Camera>
+    scene: &'a Scene,
+    camera: &'a dyn Camera,
+    controller: Option<Controller<'a>>,
+    depth_sort: DepthSort,
+    batch: bool, // merge the drawn primitives into a few meshes before painting
+    selection: Option<&'a mut Selection>,
+    highlight: egui::Stroke, // color and extra stroke width of selected nodes
+    style: DrawStyle,
 }
-impl Viewport3d {
-    pub fn with_scene(mut self, scene: Vec<Box<dyn Shape3d>>)->Self{
-        self.scene = scene;
-        self
-    }
-    pub fn with_camera(mut self, camera: Box<dyn Camera>)->Self{
-        self.camera = camera;
-        self
+impl<'a> Viewport3d<'a> {
+    pub fn new(scene: &'a Scene, camera: &'a dyn Camera)->Self{
+        Viewport3d {
+            width: 640,
+            height: 480,
+            scene,
+            camera,
+            controller: None,
+            depth_sort: DepthSort::None,
+            batch: false,
+            selection: None,
+            highlight: egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 200, 60)),
+            style: DrawStyle::new(),
+        }
     }
     pub fn with_size(mut self, width: usize,height: usize)->Self{
         self.width = width;
         self.height = height;
         self
     }
-}
-
-impl Default for Viewport3d {
-    fn default() -> Self {
-        Self {
-            width: 640,
-            height: 480,
-            scene: Vec::new(),
-            camera: Box::new( Perspective::new(0.35, Mat4::IDENTITY, 1.0, 0.01, 20.0 ))
-        }
+    pub fn with_controller(mut self, controller: CameraController)->Self{
+        self.controller = Some(Controller::Owned(controller));
+        self
+    }
+    pub fn with_controller_mut(mut self, controller: &'a mut CameraController)->Self{
+        self.controller = Some(Controller::Borrowed(controller));
+        self
+    }
+    pub fn with_depth_sort(mut self, depth_sort: DepthSort)->Self{
+        self.depth_sort = depth_sort;
+        self
+    }
+    // paints everything as a few big meshes that egui tessellates much faster than a shape per line or point,
+    // merging them costs about as much though (see examples/batching.rs) and lines lose their anti-aliasing
+    pub fn with_batching(mut self, batch: bool)->Self{
+        self.batch = batch;
+        self
+    }
+    // click, shift click and box selection of nodes, selected nodes and their children are drawn highlighted
+    pub fn with_selection(mut self, selection: &'a mut Selection)->Self{
+        self.selection = Some(selection);
+        self
+    }
+    pub fn with_highlight(mut self, highlight: egui::Stroke)->Self{
+        self.highlight = highlight;
+        self
+    }
+    pub fn with_style(mut self, style: DrawStyle)->Self{
+        self.style = style;
+        self
     }
 }
 
-impl egui::Widget for Viewport3d {
-    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+impl Viewport3d<'_> {
+    pub fn show(self, ui: &mut egui::Ui) -> ViewportResponse {
         let (response, painter) = ui.allocate_painter(
             egui::Vec2::new(self.width as f32, self.height as f32),
             egui::Sense::click_and_drag(),
         );
-        
-        let cam = self.camera.deref().clone();
-        
-        for sh in self.scene{
-            sh.draw(ui,cam,&painter, &response);
+
+        let mut camera = self.camera.box_clone();
+        camera.set_aspect_ratio(response.rect.aspect_ratio()); // fit the camera to the actual viewport size
+        // turntable navigation orbits with a plain left drag, that leaves no drag for box selection
+        let box_select = !matches!(&self.controller, Some(Controller::Owned(c)) if c.preset == NavigationPreset::Turntable)
+            && !matches!(&self.controller, Some(Controller::Borrowed(c)) if c.preset == NavigationPreset::Turntable);
+        // a drag on a gizmo handle moves the handle, the camera stays where it is
+        let gizmo_drag = gizmo::grabs_drag(&response);
+        match self.controller {
+            Some(Controller::Owned(mut controller)) => {
+                if let Some(stored) = ui.data(|d| d.get_temp::<CameraController>(response.id)){
+                    controller.xform = stored.xform;
+                    controller.pivot = stored.pivot;
+                    controller.zoom = stored.zoom;
+                }
+                if gizmo_drag { controller.apply(camera.as_mut()); } else { controller.update(&response, camera.as_mut()); }
+                ui.data_mut(|d| d.insert_temp(response.id, controller));
+            },
+            Some(Controller::Borrowed(controller)) => {
+                if gizmo_drag { controller.apply(camera.as_mut()); } else { controller.update(&response, camera.as_mut()); }
+            },
+            None => {}
+        }
+        let cam = camera.deref();
+
+        let (mut draw_list, drawn) = render::draw_scene(self.scene, cam, response.rect, self.style);
+
+        let hovered = response.hover_pos().and_then(|pointer| pick(self.scene, cam, response.rect, pointer));
+        let clicked = if response.clicked() { hovered.clone() } else { None };
+
+        let mut selection_changed = false;
+        let mut band_drawn = None;
+        if let Some(selection) = self.selection {
+            let before = selection.clone();
+            let shift = ui.input(|i| i.modifiers.shift);
+            if response.clicked() {
+                match &clicked {
+                    Some(pick) if shift => selection.toggle(pick.node),
+                    Some(pick) => selection.select(pick.node),
+                    None if !shift => selection.clear(),
+                    None => {},
+                }
+            }
+            if box_select {
+                match rubber_band(ui, &response) {
+                    Some((band, true)) => {
+                        if !shift { selection.clear(); }
+                        let bounds = draw_list.object_bounds();
+                        for id in drawn.iter() {
+                            if bounds.get(&id.index()).is_some_and(|b| b.intersects(band)) { selection.insert(*id); }
+                        }
+                    },
+                    Some((band, false)) => band_drawn = Some(band),
+                    None => {},
+                }
+            }
+            selection_changed = *selection != before;
+
+            let highlighted: HashSet<usize> = drawn.iter()
+                .filter(|id| selection.covers(self.scene, **id))
+                .map(|id| id.index())
+                .collect();
+            draw_list.highlight(&highlighted, self.highlight);
+        }
+
+        draw_list.sort(self.depth_sort);
+        if self.batch { draw_list.batch(); }
+        draw_list.paint(&painter);
+        // the band goes on top of the scene
+        if let Some(band) = band_drawn {
+            painter.add(egui::Shape::rect_filled(band, 0.0, egui::Color32::from_white_alpha(16)));
+            painter.add(egui::Shape::rect_stroke(band, 0.0, egui::Stroke::new(1.0, egui::Color32::from_white_alpha(128))));
+        }
+
+        ViewportResponse {
+            response,
+            hovered,
+            clicked,
+            camera,
+            selection_changed,
+        }
+    }
+}
+
+// tracks a plain left drag over the viewport, returns the screen rect of the band while dragging
+// and true along with it once the drag is released
+fn rubber_band(ui: &mut egui::Ui, response: &egui::Response) -> Option<(egui::Rect, bool)> {
+    let id = response.id.with("rubber_band");
+    let alt = ui.input(|i| i.modifiers.alt);
+    if response.drag_started_by(egui::PointerButton::Primary) && !alt {
+        if let Some(start) = response.interact_pointer_pos() {
+            ui.data_mut(|d| d.insert_temp(id, start));
         }
-        if response.clicked(){
-            println!("clicked {:?}", response.ctx.pointer_interact_pos());
+    }
+    let start: egui::Pos2 = ui.data(|d| d.get_temp(id))?;
+    if gizmo::grabs_drag(response) { // the drag belongs to a gizmo handle
+        ui.data_mut(|d| d.remove::<egui::Pos2>(id));
+        return None;
+    }
+    let end = response.interact_pointer_pos().or(response.hover_pos())?;
+    let band = egui::Rect::from_two_pos(start, end);
+    if response.drag_released() {
+        ui.data_mut(|d| d.remove::<egui::Pos2>(id));
+        return Some((band, true));
+    }
+    if !response.dragged() {
+        ui.data_mut(|d| d.remove::<egui::Pos2>(id));
+        return None;
+    }
+    Some((band, false))
+}
+
+// the hit closest to the camera among all visible nodes under the pointer
+fn pick(scene: &Scene, cam: &dyn Camera, rect: egui::Rect, pointer: egui::Pos2) -> Option<Pick> {
+    let mut closest: Option<(f32, Pick)> = None;
+    scene.visit_visible(|id, node, xform| {
+        let Some(sh) = &node.shape else { return; };
+        // shapes test themselves in the space of their parent node
+        let parent_space = ParentSpace::new(cam, xform);
+        let Some(local_position) = sh.pick(&parent_space, &PickQuery::new(&parent_space, rect, pointer)) else { return; };
+        let position = xform.transform_point3(local_position);
+        let dist = cam.dist_to_point(position);
+        if !matches!(&closest, Some((closest_dist, _)) if *closest_dist <= dist) {
+            closest = Some((dist, Pick {
+                node: id,
+                name: node.name.clone(),
+                position,
+            }));
         }
-        
-        response 
+    });
+    closest.map(|(_, pick)| pick)
+}
+
+impl egui::Widget for Viewport3d<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        self.show(ui).response
     }
-    
-    }
\ No newline at end of file
+}
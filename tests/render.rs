@@ -0,0 +1,193 @@
+use egui_draw3d::camera::{Orthographic, Perspective};
+use egui_draw3d::draw_context::DrawStyle;
+use egui_draw3d::draw_list::DepthSort;
+use egui_draw3d::render::render_scene;
+use egui_draw3d::scene::Scene;
+use egui_draw3d::shapes::{DisplayMode, cube::Cube, point_cloud::PointCloud, polymesh::Polymesh};
+use glam::{Vec3, Mat4};
+
+fn rect() -> egui::Rect {
+    egui::Rect::from_min_size(egui::Pos2::ZERO, egui::Vec2::new(640.0, 480.0))
+}
+
+// camera at the world position `eye` looking down -z, its xform lives in a Y flipped space
+fn camera(eye: Vec3) -> Perspective {
+    Perspective::new(0.35, Mat4::from_translation(eye * Vec3::new(1.0, -1.0, 1.0)), 0.1, 100.0)
+}
+
+fn segments(shapes: &[egui::Shape]) -> Vec<[egui::Pos2; 2]> {
+    shapes.iter().filter_map(|shape| match shape {
+        egui::Shape::LineSegment { points, .. } => Some(*points),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn projected_cube_draws_its_twelve_edges() {
+    let mut scene = Scene::new();
+    scene.add(Box::new(Cube::new(None, Mat4::IDENTITY, Vec3::ONE, 1.0, egui::Color32::WHITE)));
+
+    let shapes = render_scene(&scene, &camera(Vec3::new(0.0, 0.0, 5.0)), rect(), DepthSort::None, DrawStyle::new());
+    let lines = segments(&shapes);
+    assert_eq!(shapes.len(), 12);
+    assert_eq!(lines.len(), 12);
+    for p in lines.iter().flatten() {
+        assert!(rect().contains(*p), "{:?} is outside the viewport", p);
+    }
+}
+
+#[test]
+fn cube_cut_by_the_near_plane_keeps_only_what_is_in_front() {
+    let mut scene = Scene::new();
+    scene.add(Box::new(Cube::new(None, Mat4::IDENTITY, Vec3::ONE, 1.0, egui::Color32::WHITE)));
+
+    // the near plane sits at z = 0.45, inside the cube: the 4 front edges get dropped, the 4 running
+    // along z get cut and the 4 back edges stay as they are
+    let shapes = render_scene(&scene, &camera(Vec3::new(0.0, 0.0, 0.55)), rect(), DepthSort::None, DrawStyle::new());
+    let lines = segments(&shapes);
+    assert_eq!(lines.len(), 8);
+    for p in lines.iter().flatten() {
+        assert!(p.x.is_finite() && p.y.is_finite());
+    }
+}
+
+#[test]
+fn ground_reaching_behind_the_camera_is_clipped_to_a_polygon_in_front_of_it() {
+    // quad on the ground from far in front of the camera to far behind it, wound clockwise seen from above
+    let points = vec![
+        Vec3::new(-10.0, 0.0, -50.0),
+        Vec3::new(10.0, 0.0, -50.0),
+        Vec3::new(10.0, 0.0, 50.0),
+        Vec3::new(-10.0, 0.0, 50.0),
+    ];
+    let mut scene = Scene::new();
+    scene.add(Box::new(Polymesh::new(None, Mat4::IDENTITY, 1.0, egui::Color32::WHITE, points, vec![0, 1, 2, 3], vec![4])
+        .with_display(DisplayMode::Shaded)));
+
+    let shapes = render_scene(&scene, &camera(Vec3::new(0.0, 1.0, 0.0)), rect(), DepthSort::None, DrawStyle::new());
+    let meshes: Vec<&egui::Mesh> = shapes.iter().filter_map(|shape| match shape {
+        egui::Shape::Mesh(mesh) => Some(mesh),
+        _ => None,
+    }).collect();
+    assert_eq!(meshes.len(), 1, "{:?}", shapes);
+    let mesh = meshes[0];
+    assert!(mesh.vertices.len() >= 4);
+    assert_eq!(mesh.indices.len(), (mesh.vertices.len() - 2) * 3);
+    // the camera looks straight ahead, every point of the ground in front of it is below the horizon.
+    // Points behind the camera would have ended up mirrored above it
+    for v in mesh.vertices.iter() {
+        assert!(v.pos.x.is_finite() && v.pos.y.is_finite());
+        assert!(v.pos.y > rect().center().y, "{:?} is above the horizon", v.pos);
+    }
+}
+
+#[test]
+fn orthographic_camera_culls_the_faces_turned_away_from_it() {
+    // off to the side of the camera and turned, a perspective camera would see it from another angle
+    let xform = Mat4::from_translation(Vec3::new(-12.0, 0.0, 0.0)) * Mat4::from_rotation_y(0.6);
+    let mut scene = Scene::new();
+    scene.add(Box::new(Cube::new(None, xform, Vec3::ONE, 1.0, egui::Color32::WHITE).with_display(DisplayMode::Shaded)));
+    let cam = Orthographic::new(30.0, Mat4::from_translation(Vec3::new(0.0, 0.0, 2.0)), 0.1, 100.0);
+
+    let shapes = render_scene(&scene, &cam, rect(), DepthSort::None, DrawStyle::new());
+    let centers: Vec<f32> = shapes.iter().filter_map(|shape| match shape {
+        egui::Shape::Mesh(mesh) => Some(mesh.vertices.iter().map(|v| v.pos.x).sum::<f32>() / mesh.vertices.len() as f32),
+        _ => None,
+    }).collect();
+    // the front face right of the cube center and the -x side left of it, top and bottom are seen edge on
+    let cube_center = rect().center().x - 12.0 / 30.0 * rect().width();
+    assert_eq!(centers.len(), 2, "{:?}", shapes);
+    assert_eq!(centers.iter().filter(|x| **x < cube_center).count(), 1, "{:?}", centers);
+}
+
+#[test]
+fn point_budget_holds_below_what_a_single_octree_node_draws() {
+    // a dense ball of points, even the root of its octree alone samples more points than the budget
+    let points: Vec<Vec3> = (0..100_000).map(|i| {
+        let t = i as f32 * 0.618;
+        Vec3::new(t.sin(), (t * 1.7).cos(), (t * 2.3).sin()) * (i as f32 / 100_000.0)
+    }).collect();
+    let count_points = |budget: usize| {
+        let mut scene = Scene::new();
+        scene.add(Box::new(PointCloud::new(None, Mat4::IDENTITY, 0.01, egui::Color32::WHITE, points.clone()).with_point_budget(budget)));
+        render_scene(&scene, &camera(Vec3::new(0.0, 0.0, 5.0)), rect(), DepthSort::None, DrawStyle::new()).iter()
+            .filter(|shape| matches!(shape, egui::Shape::Circle(_)))
+            .count()
+    };
+    assert_eq!(count_points(50), 50);
+    assert_eq!(count_points(1), 1);
+    assert!(count_points(5000) <= 5000);
+}
+
+#[test]
+fn points_moved_in_place_are_found_where_they_moved_to() {
+    let points: Vec<Vec3> = (0..1000).map(|i| Vec3::new(i as f32, 0.0, 0.0)).collect();
+    let mut cloud = PointCloud::new(None, Mat4::IDENTITY, 0.01, egui::Color32::WHITE, points);
+    assert_eq!(cloud.nearest_point(Vec3::new(2000.0, 0.0, 0.0)).map(|(i, _)| i), Some(999));
+
+    // the octree was built from the old points, moving them has to rebuild it
+    cloud.points_mut()[10].x = 2000.0;
+    assert_eq!(cloud.nearest_point(Vec3::new(2000.0, 0.0, 0.0)), Some((10, 0.0)));
+}
+
+// Spread over threads or not, the drawn shapes have to be exactly the same
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_drawing_matches_serial_drawing() {
+    // several times more points than the parallel loops take per task, built on the pool
+    // because a scene can't be shared between threads
+    fn render() -> Vec<egui::Shape> {
+        let points: Vec<Vec3> = (0..20_000).map(|i| {
+            let t = i as f32 * 0.618;
+            Vec3::new(t.sin(), (t * 1.7).cos(), (t * 2.3).sin()) * (i as f32 / 20_000.0)
+        }).collect();
+        let colors = (0..points.len()).map(|i| egui::Color32::from_gray(i as u8)).collect();
+        let grid = 80;
+        let grid_points: Vec<Vec3> = (0..=grid).flat_map(|z| (0..=grid).map(move |x| Vec3::new(x as f32 / grid as f32 - 0.5, 0.0, z as f32 / grid as f32 - 0.5))).collect();
+        let indices: Vec<usize> = (0..grid).flat_map(|z| (0..grid).flat_map(move |x| {
+            let i = z * (grid + 1) + x;
+            [i, i + 1, i + grid + 2, i + grid + 1]
+        })).collect();
+        let mut scene = Scene::new();
+        scene.add(Box::new(PointCloud::new(None, Mat4::IDENTITY, 0.01, egui::Color32::WHITE, points).with_colors(colors)));
+        scene.add(Box::new(Polymesh::new(None, Mat4::from_translation(Vec3::new(0.0, -0.3, 0.0)), 1.0, egui::Color32::WHITE, grid_points, indices, vec![4; grid * grid])
+            .with_display(DisplayMode::ShadedWireframe)));
+        render_scene(&scene, &camera(Vec3::new(0.0, 0.5, 5.0)), rect(), DepthSort::PerPrimitive, DrawStyle::new())
+    }
+    let render_on = |threads: usize| rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap().install(render);
+
+    let serial = render_on(1);
+    assert!(serial.iter().filter(|shape| matches!(shape, egui::Shape::Circle(_))).count() > 10_000);
+    assert!(serial.iter().filter(|shape| matches!(shape, egui::Shape::Mesh(_))).count() > 1000);
+    assert!(serial == render_on(4));
+}
+
+#[test]
+fn per_primitive_depth_sort_paints_far_before_near() {
+    // near cube left of the camera, far cube right of it, added near first
+    let mut scene = Scene::new();
+    scene.add(Box::new(Cube::new(None, Mat4::from_translation(Vec3::new(-1.0, 0.0, -3.0)), Vec3::ONE, 1.0, egui::Color32::WHITE)));
+    scene.add(Box::new(Cube::new(None, Mat4::from_translation(Vec3::new(4.0, 0.0, -20.0)), Vec3::ONE, 1.0, egui::Color32::WHITE)));
+    let cam = camera(Vec3::new(0.0, 0.0, 5.0));
+    let center_x = rect().center().x;
+    let is_far = |line: &[egui::Pos2; 2]| line.iter().all(|p| p.x > center_x);
+
+    let unsorted = segments(&render_scene(&scene, &cam, rect(), DepthSort::None, DrawStyle::new()));
+    assert_eq!(unsorted.len(), 24);
+    assert!(!is_far(&unsorted[0]));
+
+    let sorted = segments(&render_scene(&scene, &cam, rect(), DepthSort::PerPrimitive, DrawStyle::new()));
+    assert_eq!(sorted.len(), 24);
+    assert!(sorted[..12].iter().all(is_far));
+    assert!(!sorted[12..].iter().any(is_far));
+}
+
+#[test]
+fn labels_get_laid_out() {
+    let mut scene = Scene::new();
+    scene.add(Box::new(Cube::new(Some("cube".into()), Mat4::IDENTITY, Vec3::ONE, 1.0, egui::Color32::WHITE)));
+
+    let shapes = render_scene(&scene, &camera(Vec3::new(0.0, 0.0, 5.0)), rect(), DepthSort::None, DrawStyle::new());
+    assert_eq!(shapes.len(), 13);
+    assert_eq!(shapes.iter().filter(|shape| matches!(shape, egui::Shape::Text(_))).count(), 1);
+}